@@ -1,12 +1,34 @@
 //! A simple generic heap-allocated 2D grid struct.
 
+mod algorithms;
+pub mod convolve;
+pub mod error;
+#[cfg(feature = "image")]
+mod image;
 pub mod iterators;
+mod ops;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "serde")]
+mod serde;
+mod transform;
+pub mod view;
 
-use crate::vector::Vector;
+use crate::{
+    grid::{error::GridError, iterators::Column},
+    vector::{
+        constants::{ADJACENT, ORTHOGONAL},
+        Vector,
+    },
+};
 
 use std::{
+    cmp::Ordering,
     fmt,
-    ops::{Index, IndexMut},
+    ops::{Index, IndexMut, Range},
+    str::FromStr,
 };
 
 /// A simple generic heap-allocated 2D grid struct indexed by `Vector`.
@@ -17,7 +39,7 @@ use std::{
 ///
 /// There are `width` columns and `height` rows in the grid, and the grid's iterators traverse it in row-major order.
 ///
-/// `Grid<T>` implements the [`Debug`] trait if `T` implements the [`std::fmt::Display`] trait.
+/// `Grid<T>` implements the [`Debug`] and [`std::fmt::Display`] traits if `T` implements the [`std::fmt::Display`] trait.
 ///
 /// # Examples
 ///
@@ -60,47 +82,52 @@ impl<T: Clone> Grid<T> {
     /// ```
     #[track_caller]
     pub fn new(width: i64, height: i64, value: T) -> Self {
-        let size = size(width, height);
-        let mut raw = Vec::with_capacity(size);
-        raw.resize(size, value);
-        Self {
-            raw,
-            dim: Vector::new(width, height),
-        }
+        unwrap(Self::try_new(width, height, value))
     }
-}
 
-impl<T: Default> Grid<T> {
-    /// Constructs a new `Grid<T>` with the given dimensions, initialising all values to their default value.
+    /// Constructs a new `Grid<T>` with the given dimensions, initialising all values to `value`.
     ///
-    /// Requires that `T` implements the [`Default`] trait.
+    /// Requires that `T` implements the [`Clone`] trait.
     ///
-    /// Panics if the dimensions are not positive or too large.
+    /// Returns an error if the dimensions are not positive or too large.
     ///
     /// # Examples
     ///
     /// ```
     /// use grid::prelude::*;
     ///
-    /// let grid: Grid<u8> = Grid::default(9, 3);
+    /// let grid: Grid<u8> = Grid::try_new(8, 10, 1).unwrap();
     ///
-    /// assert_eq!(grid[v(5, 1)], 0);
-    /// assert_eq!(grid[v(6, 2)], 0);
+    /// assert_eq!(grid[v(2, 4)], 1);
+    ///
+    /// assert_eq!(
+    ///     Grid::try_new(-2, 5, 1),
+    ///     Err(GridError::NonPositiveDimensions {
+    ///         width: -2,
+    ///         height: 5
+    ///     })
+    /// );
+    /// assert_eq!(
+    ///     Grid::try_new(i64::MAX, i64::MAX, 1),
+    ///     Err(GridError::CapacityOverflow {
+    ///         width: i64::MAX,
+    ///         height: i64::MAX
+    ///     })
+    /// );
     /// ```
-    #[track_caller]
-    pub fn default(width: i64, height: i64) -> Self {
-        let size = size(width, height);
+    pub fn try_new(width: i64, height: i64, value: T) -> Result<Self, GridError> {
+        let size = size(width, height)?;
         let mut raw = Vec::with_capacity(size);
-        raw.resize_with(size, Default::default);
-        Self {
+        raw.resize(size, value);
+        Ok(Self {
             raw,
             dim: Vector::new(width, height),
-        }
+        })
     }
-}
 
-impl<T> Grid<T> {
-    /// Constructs a new `Grid<T>` with the given dimensions, computing all initial values from the closure `f`.
+    /// Constructs a new `Grid<T>` with the dimensions `dim`, initialising all values to `value`.
+    ///
+    /// Equivalent to [`Grid::new`] with the coordinates of `dim` as the width and height.
     ///
     /// Panics if the dimensions are not positive or too large.
     ///
@@ -109,406 +136,3082 @@ impl<T> Grid<T> {
     /// ```
     /// use grid::prelude::*;
     ///
-    /// let grid: Grid<u8> = Grid::from_simple_fn(8, 10, || 2);
+    /// let grid: Grid<u8> = Grid::new(8, 10, 1);
+    /// let same: Grid<char> = Grid::with_dim(grid.dim(), '.');
     ///
-    /// assert_eq!(grid[v(5, 3)], 2);
-    /// assert_eq!(grid[v(1, 8)], 2);
+    /// assert!(grid.same_dim(&same));
+    /// assert_eq!(same[v(7, 9)], '.');
     /// ```
     #[track_caller]
-    pub fn from_simple_fn<F>(width: i64, height: i64, f: F) -> Self
-    where
-        F: FnMut() -> T,
-    {
-        let size = size(width, height);
-        let mut raw = Vec::with_capacity(size);
-        raw.resize_with(size, f);
-        Self {
-            raw,
-            dim: Vector::new(width, height),
-        }
+    pub fn with_dim(dim: Vector, value: T) -> Self {
+        Self::new(dim.x, dim.y, value)
     }
 
-    /// Constructs a new `Grid<T>` with the given dimensions, computing all initial values from the closure `f` which maps each position to a value.
+    /// Returns a new grid containing a copy of the rectangular region of `self` with the given top-left position and dimensions.
     ///
-    /// Panics if the dimensions are not positive or too large.
+    /// The position `(0, 0)` in the returned grid corresponds to `top_left` in `self`.
+    ///
+    /// Returns `None` if the dimensions are not positive or the region is not entirely within the bounds of the grid.
     ///
     /// # Examples
     ///
     /// ```
     /// use grid::prelude::*;
     ///
-    /// let grid: Grid<i64> = Grid::from_fn(8, 10, |pos| pos.x + pos.y);
+    /// let grid: Grid<i64> = Grid::from_fn(4, 3, |pos| pos.x + pos.y * 4);
     ///
-    /// assert_eq!(grid[v(5, 3)], 8);
-    /// assert_eq!(grid[v(7, 9)], 16);
+    /// let sub = grid.subgrid(v(1, 1), v(2, 2)).unwrap();
+    ///
+    /// assert_eq!(sub, Grid::from_nested_iter([[5, 6], [9, 10]]));
+    ///
+    /// assert_eq!(grid.subgrid(v(0, 0), v(4, 3)), Some(grid.clone()));
+    /// assert_eq!(grid.subgrid(v(3, 1), v(2, 1)), None);
+    /// assert_eq!(grid.subgrid(v(-1, 0), v(1, 1)), None);
+    /// assert_eq!(grid.subgrid(v(1, 1), v(0, 2)), None);
+    /// assert_eq!(grid.subgrid(v(1, 1), v(2, -1)), None);
     /// ```
-    #[track_caller]
-    pub fn from_fn<F>(width: i64, height: i64, mut f: F) -> Self
-    where
-        F: FnMut(Vector) -> T,
-    {
-        let mut raw = Vec::with_capacity(size(width, height));
-        for y in 0..height {
-            for x in 0..width {
-                raw.push(f(Vector::new(x, y)));
-            }
+    pub fn subgrid(&self, top_left: Vector, dim: Vector) -> Option<Self> {
+        if !self.contains_rect(top_left, dim) {
+            return None;
         }
-        Self {
-            raw,
-            dim: Vector::new(width, height),
+        let mut raw = Vec::with_capacity((dim.x * dim.y) as usize);
+        for y in top_left.y..top_left.y + dim.y {
+            let start = self.get_index(Vector::new(top_left.x, y))?;
+            raw.extend_from_slice(&self.raw[start..start + dim.x as usize]);
         }
+        Some(Self { raw, dim })
     }
 
-    /// Constructs a new `Grid<T>` with the given dimensions and values computed by an iterator in row-major order.
+    /// Returns a copy of the part of the rectangular region with the given top-left position and dimensions that
+    /// overlaps the grid, along with the position in `self` of the top left of that part.
     ///
-    /// Panics if the dimensions are not positive or too large.
+    /// Unlike [`subgrid`](Self::subgrid), the region may extend past the edges of the grid.
+    /// Returns `None` only if the region does not overlap the grid at all.
     ///
-    /// Panics if the iterator runs out before the grid is filled.
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::from_fn(4, 3, |pos| pos.x + pos.y * 4);
+    ///
+    /// let (top_left, sub) = grid.subgrid_clipped(v(-1, 1), v(3, 5)).unwrap();
+    ///
+    /// assert_eq!(top_left, v(0, 1));
+    /// assert_eq!(sub, Grid::from_nested_iter([[4, 5], [8, 9]]));
+    ///
+    /// assert_eq!(grid.subgrid_clipped(v(1, 1), v(2, 2)), Some((v(1, 1), grid.subgrid(v(1, 1), v(2, 2)).unwrap())));
+    /// assert_eq!(grid.subgrid_clipped(v(-5, -5), v(20, 20)), Some((v(0, 0), grid.clone())));
+    /// assert_eq!(grid.subgrid_clipped(v(4, 0), v(2, 2)), None);
+    /// assert_eq!(grid.subgrid_clipped(v(1, 1), v(0, 2)), None);
+    /// ```
+    pub fn subgrid_clipped(&self, top_left: Vector, dim: Vector) -> Option<(Vector, Self)> {
+        let (start, end) = self.clip_rect(top_left, dim)?;
+        Some((start, self.subgrid(start, end - start)?))
+    }
+
+    /// Splits the grid into non-overlapping tiles of dimensions `chunk_dim`, returning a grid of copies of those tiles.
+    ///
+    /// The tile at position `(cx, cy)` of the returned grid has its top-left at `(cx * chunk_dim.x, cy * chunk_dim.y)` in `self`.
+    /// If the dimensions of `self` are not multiples of `chunk_dim`, the tiles in the last column and row are
+    /// narrower and shorter respectively, containing only the remaining values, so every value is in exactly one tile.
+    ///
+    /// Panics if the chunk dimensions are not positive or the grid is empty.
     ///
     /// # Examples
     ///
     /// ```
     /// use grid::prelude::*;
     ///
-    /// let grid: Grid<u8> = Grid::from_iter(2, 3, [1, 2, 3, 4, 5, 6]);
+    /// let grid: Grid<i64> = Grid::from_fn(5, 3, |pos| pos.x + pos.y * 5);
     ///
-    /// assert_eq!(grid[v(1, 0)], 2);
-    /// assert_eq!(grid[v(0, 2)], 5);
-    /// assert_eq!(grid[v(1, 2)], 6);
+    /// let chunks = grid.chunks(v(2, 2));
+    ///
+    /// assert_eq!(chunks.dim(), v(3, 2));
+    /// assert_eq!(chunks[v(0, 0)], Grid::from_nested_iter([[0, 1], [5, 6]]));
+    /// assert_eq!(chunks[v(2, 0)].dim(), v(1, 2));
+    /// assert_eq!(chunks[v(2, 1)], Grid::from_nested_iter([[14]]));
+    ///
+    /// let rows: Vec<Grid<i64>> = chunks.rows().map(|row| Grid::hstack_all(row).unwrap()).collect();
+    ///
+    /// assert_eq!(Grid::vstack_all(&rows), Some(grid.clone()));
+    ///
+    /// assert_eq!(grid.chunks(v(i64::MAX, 1)).dim(), v(1, 3));
     /// ```
     #[track_caller]
-    pub fn from_iter<I>(width: i64, height: i64, values: I) -> Self
-    where
-        I: IntoIterator<Item = T>,
-    {
-        let size = size(width, height);
-        let mut raw = Vec::with_capacity(size);
-        let mut values = values.into_iter();
-        for _ in 0..size {
-            raw.push(values.next().expect("iterator too short"));
-        }
-        Self {
-            raw,
-            dim: Vector::new(width, height),
-        }
+    pub fn chunks(&self, chunk_dim: Vector) -> Grid<Self> {
+        assert!(
+            chunk_dim.x > 0 && chunk_dim.y > 0,
+            "chunk dimensions must be positive: {chunk_dim}"
+        );
+        assert!(!self.is_empty(), "cannot split an empty grid into chunks");
+        let count = (self.dim - Vector::new(1, 1)).component_div(chunk_dim) + Vector::new(1, 1);
+        Grid::from_fn(count.x, count.y, |pos| {
+            let top_left = pos.component_mul(chunk_dim);
+            let dim = chunk_dim.min(self.dim - top_left);
+            self.subgrid(top_left, dim)
+                .expect("chunk is within the bounds of the grid")
+        })
     }
 
-    /// Constructs a new `Grid<T>` from an iterator of iterators, where each inner iterator defines a row.
+    /// Constructs a new `Grid<T>` with the given dimensions, initialising all values to `fill` and then
+    /// setting the value at each position yielded by the iterator.
     ///
-    /// Panics if not all inner iterators are the same length.
+    /// Positions that are out of bounds are ignored, and later values overwrite earlier values at the same position.
+    ///
+    /// Panics if the dimensions are not positive or too large.
     ///
     /// # Examples
     ///
     /// ```
     /// use grid::prelude::*;
     ///
-    /// let values = [
-    ///     [1, 2, 3],
-    ///     [4, 5, 6],
-    /// ];
+    /// let grid: Grid<u8> = Grid::from_positions(3, 2, 0, [(v(1, 0), 4), (v(2, 1), 7), (v(5, 5), 9)]);
     ///
-    /// let grid: Grid<u8> = Grid::from_nested_iter(values);
+    /// assert_eq!(grid, Grid::from_nested_iter([[0, 4, 0], [0, 0, 7]]));
     ///
-    /// assert_eq!(grid.width(), 3);
-    /// assert_eq!(grid.height(), 2);
-    /// assert_eq!(grid[v(2, 1)], 6);
+    /// let copy = Grid::from_positions(3, 2, 0, grid.clone().into_iter_positions());
+    ///
+    /// assert_eq!(copy, grid);
     /// ```
     #[track_caller]
-    pub fn from_nested_iter<I, J>(values: I) -> Self
+    pub fn from_positions<I>(width: i64, height: i64, fill: T, values: I) -> Self
     where
-        I: IntoIterator<Item = J>,
-        J: IntoIterator<Item = T>,
+        I: IntoIterator<Item = (Vector, T)>,
     {
-        let mut values = values.into_iter();
-        let mut raw = Vec::new();
-        let Some(first) = values.next() else {
-            return Self {
-                raw,
-                dim: Vector::new(0, 0),
-            };
-        };
-        for value in first.into_iter() {
-            raw.push(value);
-        }
-        let width = raw.len() as i64;
-        let mut height = 1;
-        for inner in values {
-            height += 1;
-            let mut count = 0;
-            for value in inner.into_iter() {
-                count += 1;
-                raw.push(value);
-            }
-            if count != width {
-                panic!("not all inner iterators are the same length");
-            }
-        }
-        Self {
-            raw,
-            dim: Vector::new(width, height),
+        let mut grid = Self::new(width, height, fill);
+        for (pos, value) in values {
+            grid.set(pos, value);
         }
+        grid
     }
 
-    /// Returns the width of the grid.
+    /// Constructs a new `Grid<T>` with the given dimensions, initialising all values to `fill` and then
+    /// setting the value at each position yielded by the iterator.
+    ///
+    /// Later values overwrite earlier values at the same position.
+    ///
+    /// Returns an error if the dimensions are not positive or too large, or if any position is out of bounds.
     ///
     /// # Examples
     ///
     /// ```
     /// use grid::prelude::*;
     ///
-    /// let grid: Grid<u8> = Grid::new(8, 10, 9);
+    /// let grid: Grid<u8> = Grid::try_from_positions(3, 2, 0, [(v(1, 0), 4), (v(2, 1), 7)]).unwrap();
     ///
-    /// assert_eq!(grid.width(), 8);
+    /// assert_eq!(grid, Grid::from_nested_iter([[0, 4, 0], [0, 0, 7]]));
+    ///
+    /// assert_eq!(
+    ///     Grid::try_from_positions(3, 2, 0, [(v(1, 0), 4), (v(3, 1), 7)]),
+    ///     Err(GridError::OutOfBounds {
+    ///         pos: v(3, 1),
+    ///         dim: v(3, 2)
+    ///     })
+    /// );
     /// ```
-    #[inline]
-    pub fn width(&self) -> i64 {
-        self.dim.x
+    pub fn try_from_positions<I>(
+        width: i64,
+        height: i64,
+        fill: T,
+        values: I,
+    ) -> Result<Self, GridError>
+    where
+        I: IntoIterator<Item = (Vector, T)>,
+    {
+        let mut grid = Self::try_new(width, height, fill)?;
+        for (pos, value) in values {
+            if grid.set(pos, value).is_none() {
+                return Err(GridError::OutOfBounds { pos, dim: grid.dim });
+            }
+        }
+        Ok(grid)
     }
 
-    /// Returns the height of the grid.
+    /// Sets every value in the grid to `value`.
     ///
     /// # Examples
     ///
     /// ```
     /// use grid::prelude::*;
     ///
-    /// let grid: Grid<u8> = Grid::new(8, 10, 10);
+    /// let mut grid: Grid<i64> = Grid::from_fn(3, 2, |pos| pos.x + pos.y);
     ///
-    /// assert_eq!(grid.height(), 10);
+    /// grid.fill(7);
+    ///
+    /// assert_eq!(grid, Grid::new(3, 2, 7));
     /// ```
-    #[inline]
-    pub fn height(&self) -> i64 {
-        self.dim.y
+    pub fn fill(&mut self, value: T) {
+        self.raw.fill(value);
     }
 
-    /// Returns the dimensions of the grid.
+    /// Sets every value in the grid to `value`, keeping the existing allocation and dimensions.
+    ///
+    /// This is equivalent to [`Grid::fill`], and is the counterpart of [`Grid::reset`] for types that do not implement [`Default`].
     ///
     /// # Examples
     ///
     /// ```
     /// use grid::prelude::*;
     ///
-    /// let grid: Grid<u8> = Grid::new(8, 10, 11);
+    /// let mut grid: Grid<char> = Grid::from_char_str("#.\n.#").unwrap();
     ///
-    /// assert_eq!(grid.dim(), v(8, 10));
+    /// grid.clear_to('.');
+    ///
+    /// assert_eq!(grid, Grid::new(2, 2, '.'));
     /// ```
-    #[inline]
-    pub fn dim(&self) -> Vector {
-        self.dim
+    pub fn clear_to(&mut self, value: T) {
+        self.fill(value);
     }
 
-    /// Returns a reference to the value at the given position of the grid, or `None` if out of bounds.
+    /// Sets every value in the rectangular region with the given top-left position and dimensions to `value`.
+    ///
+    /// Any part of the region outside the bounds of the grid is clipped.
+    /// Returns `true` if the region was entirely within the bounds of the grid, or `false` if it was clipped or had non-positive dimensions.
     ///
     /// # Examples
     ///
     /// ```
     /// use grid::prelude::*;
     ///
-    /// let mut grid: Grid<u8> = Grid::new(8, 10, 3);
+    /// let mut grid: Grid<u8> = Grid::new(4, 3, 0);
     ///
-    /// grid[v(1, 1)] = 4;
+    /// assert_eq!(grid.fill_region(v(1, 1), v(2, 2), 1), true);
+    /// assert_eq!(grid.fill_region(v(-1, -1), v(2, 2), 2), false);
+    /// assert_eq!(grid.fill_region(v(5, 1), v(2, 2), 3), false);
     ///
-    /// assert_eq!(grid.get(v(5, 2)), Some(&3));
-    /// assert_eq!(grid.get(v(1, 1)), Some(&4));
-    /// assert_eq!(grid.get(v(8, 6)), None);
-    /// assert_eq!(grid.get(v(4, 10)), None);
-    /// assert_eq!(grid.get(v(-2, 3)), None);
+    /// assert_eq!(
+    ///     grid,
+    ///     Grid::from_nested_iter([
+    ///         [2, 0, 0, 0],
+    ///         [0, 1, 1, 0],
+    ///         [0, 1, 1, 0],
+    ///     ])
+    /// );
     /// ```
-    pub fn get(&self, pos: Vector) -> Option<&T> {
-        Some(&self.raw[self.get_index(pos)?])
+    pub fn fill_region(&mut self, top_left: Vector, dim: Vector, value: T) -> bool {
+        if let Some((start, end)) = self.clip_rect(top_left, dim) {
+            let width = self.width() as usize;
+            for y in start.y..end.y {
+                let row = y as usize * width;
+                self.raw[row + start.x as usize..row + end.x as usize].fill(value.clone());
+            }
+        }
+        self.contains_rect(top_left, dim)
     }
 
-    /// Returns a mutable reference to the value at the given position of the grid, or `None` if out of bounds.
+    /// Copies `values` into the row at the given `y` coordinate.
+    ///
+    /// Returns `false` and leaves the grid unchanged if `y` is out of bounds or the length of `values` is not equal to
+    /// the width of the grid.
     ///
     /// # Examples
     ///
     /// ```
     /// use grid::prelude::*;
     ///
-    /// let mut grid: Grid<u8> = Grid::new(8, 10, 4);
+    /// let mut grid: Grid<u8> = Grid::new(3, 2, 0);
     ///
-    /// grid[v(5, 3)] = 2;
+    /// assert_eq!(grid.set_row(1, &[4, 5, 6]), true);
+    /// assert_eq!(grid.set_row(0, &[1, 2]), false);
+    /// assert_eq!(grid.set_row(0, &[1, 2, 3, 4]), false);
+    /// assert_eq!(grid.set_row(2, &[1, 2, 3]), false);
     ///
-    /// assert_eq!(grid.get_mut(v(5, 3)), Some(&mut 2));
-    /// assert_eq!(grid.get_mut(v(0, 0)), Some(&mut 4));
-    /// assert_eq!(grid.get_mut(v(1, 10)), None);
-    /// assert_eq!(grid.get_mut(v(9, 7)), None);
-    /// assert_eq!(grid.get_mut(v(4, -1)), None);
+    /// assert_eq!(grid, Grid::from_nested_iter([[0, 0, 0], [4, 5, 6]]));
     /// ```
-    pub fn get_mut(&mut self, pos: Vector) -> Option<&mut T> {
-        let index = self.get_index(pos)?;
-        Some(&mut self.raw[index])
+    pub fn set_row(&mut self, y: i64, values: &[T]) -> bool {
+        match self.get_row_mut(y) {
+            Some(row) if row.len() == values.len() => {
+                row.clone_from_slice(values);
+                true
+            }
+            _ => false,
+        }
     }
 
-    /// Sets the value at the given position of the grid.
+    /// Copies `values` into the column at the given `x` coordinate.
     ///
-    /// Returns the old value at that position, or `None` if out of bounds.
+    /// Returns `false` and leaves the grid unchanged if `x` is out of bounds or the length of `values` is not equal to
+    /// the height of the grid.
     ///
     /// # Examples
     ///
     /// ```
     /// use grid::prelude::*;
     ///
-    /// let mut grid: Grid<u8> = Grid::new(8, 10, 5);
+    /// let mut grid: Grid<u8> = Grid::new(3, 2, 0);
     ///
-    /// assert_eq!(grid.set(v(2, 3), 7), Some(5));
-    /// assert_eq!(grid.set(v(9, 12), 1), None);
-    /// assert_eq!(grid.set(v(-4, -7), 3), None);
+    /// assert_eq!(grid.set_col(2, &[7, 8]), true);
+    /// assert_eq!(grid.set_col(0, &[1]), false);
+    /// assert_eq!(grid.set_col(-1, &[1, 2]), false);
     ///
-    /// assert_eq!(grid[v(2, 3)], 7);
+    /// assert_eq!(grid, Grid::from_nested_iter([[0, 0, 7], [0, 0, 8]]));
     /// ```
-    pub fn set(&mut self, pos: Vector, value: T) -> Option<T> {
-        Some(std::mem::replace(self.get_mut(pos)?, value))
+    pub fn set_col(&mut self, x: i64, values: &[T]) -> bool {
+        if !(0..self.width()).contains(&x) || values.len() != self.height() as usize {
+            return false;
+        }
+        let width = self.width() as usize;
+        for (value, new) in self.raw[x as usize..].iter_mut().step_by(width).zip(values) {
+            value.clone_from(new);
+        }
+        true
     }
 
-    /// Returns `true` if the given position is within the bounds of the grid, or `false` otherwise.
+    /// Copies the values of `other` into the grid, with the top left of `other` placed at `at`.
+    ///
+    /// Any part of `other` that falls outside the grid is clipped.
+    /// See [`overlay`](Self::overlay) to control how values are combined.
     ///
     /// # Examples
     ///
     /// ```
     /// use grid::prelude::*;
     ///
-    /// let grid: Grid<u8> = Grid::new(15, 14, 11);
+    /// let mut grid: Grid<u8> = Grid::new(4, 3, 0);
+    /// let stamp: Grid<u8> = Grid::new(3, 3, 1);
     ///
-    /// assert_eq!(grid.in_bounds(v(0, 0)), true);
-    /// assert_eq!(grid.in_bounds(v(10, 4)), true);
-    /// assert_eq!(grid.in_bounds(v(15, 2)), false);
-    /// assert_eq!(grid.in_bounds(v(3, 17)), false);
-    /// assert_eq!(grid.in_bounds(v(-1, 5)), false);
-    /// assert_eq!(grid.in_bounds(v(-15, -14)), false);
+    /// grid.overlay_copy(&stamp, v(2, 1));
+    ///
+    /// assert_eq!(
+    ///     grid,
+    ///     Grid::from_nested_iter([
+    ///         [0, 0, 0, 0],
+    ///         [0, 0, 1, 1],
+    ///         [0, 0, 1, 1],
+    ///     ])
+    /// );
     /// ```
-    pub fn in_bounds(&self, pos: Vector) -> bool {
-        (0..self.width()).contains(&pos.x) && (0..self.height()).contains(&pos.y)
-    }
-
-    fn get_index(&self, pos: Vector) -> Option<usize> {
-        self.in_bounds(pos)
-            .then(|| pos.x as usize + ((pos.y as usize) * (self.width() as usize)))
+    pub fn overlay_copy(&mut self, other: &Self, at: Vector) {
+        self.overlay(other, at, T::clone_from);
     }
 
-    /// Maps the values of an existing grid to create a new grid with the same dimensions.
+    /// Changes the dimensions of the grid, keeping the values at positions that are within both the old and new bounds
+    /// and initialising all other values to `fill`.
+    ///
+    /// Panics if the new dimensions are not positive or too large.
     ///
     /// # Examples
     ///
     /// ```
     /// use grid::prelude::*;
     ///
-    /// let grid_a: Grid<u8> = Grid::new(15, 14, 11);
+    /// let mut grid: Grid<u8> = Grid::from_nested_iter([[1, 2], [3, 4]]);
     ///
-    /// let grid_b = grid_a.map(|value| *value + 2);
+    /// grid.resize(v(3, 3), 0);
     ///
-    /// assert_eq!(grid_b[v(2, 3)], 13);
+    /// assert_eq!(grid, Grid::from_nested_iter([[1, 2, 0], [3, 4, 0], [0, 0, 0]]));
     ///
-    /// let grid_c = grid_b.map(ToString::to_string);
+    /// grid.resize(v(1, 4), 9);
     ///
-    /// assert_eq!(&grid_c[v(2, 3)], "13");
+    /// assert_eq!(grid, Grid::from_nested_iter([[1], [3], [0], [9]]));
+    ///
+    /// grid.resize(v(2, 1), 5);
+    ///
+    /// assert_eq!(grid, Grid::from_nested_iter([[1, 5]]));
     /// ```
-    pub fn map<F, U>(&self, mut f: F) -> Grid<U>
-    where
-        F: FnMut(&T) -> U,
-    {
-        let mut raw = Vec::with_capacity(self.raw.len());
-        for value in self {
-            raw.push(f(value));
+    #[track_caller]
+    pub fn resize(&mut self, new_dim: Vector, fill: T) {
+        let size = unwrap(size(new_dim.x, new_dim.y));
+        let old_width = self.width() as usize;
+        let new_width = new_dim.x as usize;
+        let kept = old_width.min(new_width);
+        let mut old = std::mem::take(&mut self.raw).into_iter();
+        let mut raw = Vec::with_capacity(size);
+        for _ in 0..new_dim.y {
+            let start = raw.len();
+            let mut row = old.by_ref().take(old_width);
+            raw.extend(row.by_ref().take(kept));
+            row.for_each(drop);
+            raw.resize(start + new_width, fill.clone());
         }
-        Grid { raw, dim: self.dim }
+        self.raw = raw;
+        self.dim = new_dim;
     }
 
-    /// Maps the values and positions of an existing grid to create a new grid with the same dimensions.
+    /// Inserts a new row with every value set to `value` at the given `y` coordinate, moving all rows below it down by one.
+    ///
+    /// Inserting at `y == height` appends the row without moving any existing values. As with [`Vec::push`],
+    /// spare capacity is reused and grown geometrically, so building a grid by repeatedly appending rows takes amortised
+    /// `O(width)` time per row. Capacity for a known number of rows can be allocated up front with [`Grid::reserve_rows`].
+    ///
+    /// Panics if `y` is negative or greater than `height`.
     ///
     /// # Examples
     ///
     /// ```
     /// use grid::prelude::*;
     ///
-    /// let grid_a: Grid<i64> = Grid::new(5, 6, 3);
-    ///
-    /// let grid_b = grid_a.pos_map(|pos, value| *value + pos.x);
-    ///
-    /// assert_eq!(grid_b[v(1, 4)], 4);
-    /// assert_eq!(grid_b[v(3, 0)], 6);
+    /// let mut grid: Grid<u8> = Grid::from_nested_iter([[1, 2], [3, 4]]);
     ///
-    /// let grid_c = grid_b.pos_map(|pos, value| *value + pos.y);
+    /// grid.insert_row(1, 0);
+    /// grid.insert_row(3, 9);
     ///
-    /// assert_eq!(grid_c[v(1, 4)], 8);
+    /// assert_eq!(grid, Grid::from_nested_iter([[1, 2], [0, 0], [3, 4], [9, 9]]));
     /// ```
-    pub fn pos_map<F, U>(&self, mut f: F) -> Grid<U>
+    #[track_caller]
+    pub fn insert_row(&mut self, y: i64, value: T) {
+        let (width, height) = (self.width(), self.height());
+        if !(0..=height).contains(&y) {
+            panic!(
+                "row insertion index out of bounds: the height is {height} but the index is {y}"
+            );
+        }
+        let index = (y * width) as usize;
+        self.raw
+            .splice(index..index, std::iter::repeat_n(value, width as usize));
+        self.dim.y += 1;
+    }
+
+    /// Inserts a new column with every value set to `value` at the given `x` coordinate, moving all columns to the right of it right by one.
+    ///
+    /// Unlike [`Grid::insert_row`], this moves almost every value in the grid, so each call takes `O(width * height)`
+    /// time even when appending a column. Spare capacity is reused as with [`Vec::push`].
+    ///
+    /// Panics if `x` is negative or greater than `width`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<u8> = Grid::from_nested_iter([[1, 2], [3, 4]]);
+    ///
+    /// grid.insert_col(0, 0);
+    /// grid.insert_col(3, 9);
+    ///
+    /// assert_eq!(grid, Grid::from_nested_iter([[0, 1, 2, 9], [0, 3, 4, 9]]));
+    ///
+    /// grid.insert_col(2, 5);
+    ///
+    /// assert_eq!(grid, Grid::from_nested_iter([[0, 1, 5, 2, 9], [0, 3, 5, 4, 9]]));
+    /// ```
+    #[track_caller]
+    pub fn insert_col(&mut self, x: i64, value: T) {
+        let (width, height) = (self.width(), self.height());
+        if !(0..=width).contains(&x) {
+            panic!(
+                "column insertion index out of bounds: the width is {width} but the index is {x}"
+            );
+        }
+        let (width, x) = (width as usize, x as usize);
+        let old_len = self.raw.len();
+        self.raw.resize(old_len + height as usize, value);
+        // move each value right into its final position, working backwards so that the slots between the last
+        // value read and the last slot written always hold copies of `value`
+        let mut write = self.raw.len();
+        for y in (0..height as usize).rev() {
+            let row = y * width;
+            for read in (row + x..row + width).rev() {
+                write -= 1;
+                self.raw.swap(read, write);
+            }
+            write -= 1;
+            for read in (row..row + x).rev() {
+                write -= 1;
+                self.raw.swap(read, write);
+            }
+        }
+        self.dim.x += 1;
+    }
+
+    /// Returns a new grid with the values of `other` placed to the right of the values of `self`.
+    ///
+    /// Returns `None` if the grids have different heights.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let a: Grid<u8> = Grid::from_nested_iter([[1, 2], [3, 4]]);
+    /// let b: Grid<u8> = Grid::from_nested_iter([[5], [6]]);
+    ///
+    /// assert_eq!(a.hstack(&b), Some(Grid::from_nested_iter([[1, 2, 5], [3, 4, 6]])));
+    /// assert_eq!(a.hstack(&Grid::new(2, 3, 0)), None);
+    /// ```
+    pub fn hstack(&self, other: &Self) -> Option<Self> {
+        if self.height() != other.height() {
+            return None;
+        }
+        let mut raw = Vec::with_capacity(self.raw.len() + other.raw.len());
+        for (left, right) in self.rows().zip(other.rows()) {
+            raw.extend_from_slice(left);
+            raw.extend_from_slice(right);
+        }
+        Some(Self {
+            raw,
+            dim: Vector::new(self.width() + other.width(), self.height()),
+        })
+    }
+
+    /// Returns a new grid with the values of `other` placed below the values of `self`.
+    ///
+    /// Returns `None` if the grids have different widths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let a: Grid<u8> = Grid::from_nested_iter([[1, 2], [3, 4]]);
+    /// let b: Grid<u8> = Grid::from_nested_iter([[5, 6]]);
+    ///
+    /// assert_eq!(a.vstack(&b), Some(Grid::from_nested_iter([[1, 2], [3, 4], [5, 6]])));
+    /// assert_eq!(a.vstack(&Grid::new(3, 2, 0)), None);
+    /// ```
+    pub fn vstack(&self, other: &Self) -> Option<Self> {
+        if self.width() != other.width() {
+            return None;
+        }
+        let mut raw = Vec::with_capacity(self.raw.len() + other.raw.len());
+        raw.extend_from_slice(&self.raw);
+        raw.extend_from_slice(&other.raw);
+        Some(Self {
+            raw,
+            dim: Vector::new(self.width(), self.height() + other.height()),
+        })
+    }
+
+    /// Returns a new grid with the values of each grid placed to the right of the values of the previous grid.
+    ///
+    /// Returns `None` if there are no grids or the grids have different heights.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let tiles: Vec<Grid<u8>> = (1..=3).map(|i| Grid::new(1, 2, i)).collect();
+    ///
+    /// assert_eq!(
+    ///     Grid::hstack_all(&tiles),
+    ///     Some(Grid::from_nested_iter([[1, 2, 3], [1, 2, 3]]))
+    /// );
+    /// assert_eq!(Grid::<u8>::hstack_all(&[]), None);
+    /// ```
+    pub fn hstack_all(grids: &[Self]) -> Option<Self> {
+        let height = grids.first()?.height();
+        if grids.iter().any(|grid| grid.height() != height) {
+            return None;
+        }
+        let width = grids.iter().map(Self::width).sum();
+        let mut raw = Vec::with_capacity(grids.iter().map(|grid| grid.raw.len()).sum());
+        for y in 0..height {
+            for grid in grids {
+                raw.extend_from_slice(grid.get_row(y)?);
+            }
+        }
+        Some(Self {
+            raw,
+            dim: Vector::new(width, height),
+        })
+    }
+
+    /// Returns a new grid with the values of each grid placed below the values of the previous grid.
+    ///
+    /// Returns `None` if there are no grids or the grids have different widths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let tiles: Vec<Grid<u8>> = (1..=3).map(|i| Grid::new(2, 1, i)).collect();
+    ///
+    /// assert_eq!(
+    ///     Grid::vstack_all(&tiles),
+    ///     Some(Grid::from_nested_iter([[1, 1], [2, 2], [3, 3]]))
+    /// );
+    /// assert_eq!(Grid::<u8>::vstack_all(&[]), None);
+    /// ```
+    pub fn vstack_all(grids: &[Self]) -> Option<Self> {
+        let width = grids.first()?.width();
+        if grids.iter().any(|grid| grid.width() != width) {
+            return None;
+        }
+        let height = grids.iter().map(Self::height).sum();
+        let mut raw = Vec::with_capacity(grids.iter().map(|grid| grid.raw.len()).sum());
+        for grid in grids {
+            raw.extend_from_slice(&grid.raw);
+        }
+        Some(Self {
+            raw,
+            dim: Vector::new(width, height),
+        })
+    }
+
+    /// Creates a new grid with the same dimensions, keeping a clone of each value for which `pred` returns `true`
+    /// and replacing the rest with `None`.
+    ///
+    /// `pred` is given the position as well as the value, so spatial masks can be expressed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::from_fn(5, 5, |pos| pos.x + pos.y);
+    ///
+    /// let circle = grid.mask(|pos, _| (pos - v(2, 2)).length_squared() <= 2);
+    ///
+    /// assert_eq!(circle.dim(), grid.dim());
+    /// assert_eq!(circle.count(Option::is_some), 9);
+    /// assert_eq!(circle[v(1, 1)], Some(2));
+    /// assert_eq!(circle[v(0, 2)], None);
+    ///
+    /// let even = grid.mask(|_, value| value % 2 == 0).map(|value| value.unwrap_or(-1));
+    ///
+    /// assert_eq!(even[v(1, 0)], -1);
+    /// assert_eq!(even[v(3, 1)], 4);
+    /// ```
+    pub fn mask<F>(&self, mut pred: F) -> Grid<Option<T>>
+    where
+        F: FnMut(Vector, &T) -> bool,
+    {
+        self.pos_map(|pos, value| pred(pos, value).then(|| value.clone()))
+    }
+}
+
+impl<T: Default> Grid<T> {
+    /// Constructs a new `Grid<T>` with the given dimensions, initialising all values to their default value.
+    ///
+    /// Requires that `T` implements the [`Default`] trait.
+    ///
+    /// Panics if the dimensions are not positive or too large.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::default(9, 3);
+    ///
+    /// assert_eq!(grid[v(5, 1)], 0);
+    /// assert_eq!(grid[v(6, 2)], 0);
+    /// ```
+    #[track_caller]
+    pub fn default(width: i64, height: i64) -> Self {
+        unwrap(Self::try_default(width, height))
+    }
+
+    /// Constructs a new `Grid<T>` with the given dimensions, initialising all values to their default value.
+    ///
+    /// Requires that `T` implements the [`Default`] trait.
+    ///
+    /// Returns an error if the dimensions are not positive or too large.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::try_default(9, 3).unwrap();
+    ///
+    /// assert_eq!(grid[v(5, 1)], 0);
+    ///
+    /// assert_eq!(
+    ///     Grid::<u8>::try_default(9, 0),
+    ///     Err(GridError::NonPositiveDimensions {
+    ///         width: 9,
+    ///         height: 0
+    ///     })
+    /// );
+    /// ```
+    pub fn try_default(width: i64, height: i64) -> Result<Self, GridError> {
+        let size = size(width, height)?;
+        let mut raw = Vec::with_capacity(size);
+        raw.resize_with(size, Default::default);
+        Ok(Self {
+            raw,
+            dim: Vector::new(width, height),
+        })
+    }
+
+    /// Constructs a new `Grid<T>` with the dimensions `dim`, initialising all values to their default value.
+    ///
+    /// Equivalent to [`Grid::default`] with the coordinates of `dim` as the width and height.
+    ///
+    /// Panics if the dimensions are not positive or too large.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::default_with_dim(v(9, 3));
+    ///
+    /// assert_eq!(grid.dim(), v(9, 3));
+    /// assert_eq!(grid[v(5, 1)], 0);
+    /// ```
+    #[track_caller]
+    pub fn default_with_dim(dim: Vector) -> Self {
+        Self::default(dim.x, dim.y)
+    }
+
+    /// Sets every value in the grid to its default value, keeping the existing allocation and dimensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<i64> = Grid::from_fn(3, 2, |pos| pos.x + pos.y);
+    ///
+    /// grid.reset();
+    ///
+    /// assert_eq!(grid, Grid::default(3, 2));
+    /// ```
+    pub fn reset(&mut self) {
+        self.raw.fill_with(Default::default);
+    }
+
+    /// Moves the value out of the given position of the grid, leaving its default value in its place.
+    ///
+    /// Returns the old value at that position, or `None` if out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<Option<String>> = Grid::default(3, 2);
+    /// grid[v(1, 0)] = Some("crate".to_string());
+    ///
+    /// let entity = grid.take(v(1, 0)).flatten();
+    /// grid.set(v(2, 1), entity);
+    ///
+    /// assert_eq!(grid[v(1, 0)], None);
+    /// assert_eq!(grid[v(2, 1)].as_deref(), Some("crate"));
+    /// assert_eq!(grid.take(v(3, 0)), None);
+    /// ```
+    pub fn take(&mut self, pos: Vector) -> Option<T> {
+        Some(std::mem::take(self.get_mut(pos)?))
+    }
+}
+
+impl<T> Grid<T> {
+    /// Constructs a new `Grid<T>` with the given dimensions, computing all initial values from the closure `f`.
+    ///
+    /// Panics if the dimensions are not positive or too large.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::from_simple_fn(8, 10, || 2);
+    ///
+    /// assert_eq!(grid[v(5, 3)], 2);
+    /// assert_eq!(grid[v(1, 8)], 2);
+    /// ```
+    #[track_caller]
+    pub fn from_simple_fn<F>(width: i64, height: i64, f: F) -> Self
+    where
+        F: FnMut() -> T,
+    {
+        let size = unwrap(size(width, height));
+        let mut raw = Vec::with_capacity(size);
+        raw.resize_with(size, f);
+        Self {
+            raw,
+            dim: Vector::new(width, height),
+        }
+    }
+
+    /// Constructs a new `Grid<T>` with the given dimensions, computing all initial values from the closure `f`
+    /// which maps each row-major index to a value.
+    ///
+    /// `f` is called with `0, 1, 2, ...` in order, where index `i` is the position `(i % width, i / width)`,
+    /// so the position `pos` has index `pos.x + pos.y * width`.
+    ///
+    /// Panics if the dimensions are not positive or too large.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let flat = [3, 1, 4, 1, 5, 9];
+    ///
+    /// let grid: Grid<u8> = Grid::from_index_fn(3, 2, |i| flat[i]);
+    ///
+    /// assert_eq!(grid, Grid::from_nested_iter([[3, 1, 4], [1, 5, 9]]));
+    /// assert_eq!(grid[v(2, 1)], flat[2 + 1 * 3]);
+    /// ```
+    #[track_caller]
+    pub fn from_index_fn<F>(width: i64, height: i64, f: F) -> Self
+    where
+        F: FnMut(usize) -> T,
+    {
+        let size = unwrap(size(width, height));
+        Self {
+            raw: (0..size).map(f).collect(),
+            dim: Vector::new(width, height),
+        }
+    }
+
+    /// Constructs a new `Grid<T>` with the given dimensions, computing all initial values from the closure `f` which maps each position to a value.
+    ///
+    /// Panics if the dimensions are not positive or too large.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::from_fn(8, 10, |pos| pos.x + pos.y);
+    ///
+    /// assert_eq!(grid[v(5, 3)], 8);
+    /// assert_eq!(grid[v(7, 9)], 16);
+    /// ```
+    #[track_caller]
+    pub fn from_fn<F>(width: i64, height: i64, f: F) -> Self
+    where
+        F: FnMut(Vector) -> T,
+    {
+        unwrap(Self::try_from_fn(width, height, f))
+    }
+
+    /// Constructs a new `Grid<T>` with the given dimensions, computing all initial values from the closure `f` which maps each position to a value.
+    ///
+    /// Returns an error if the dimensions are not positive or too large.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::try_from_fn(8, 10, |pos| pos.x + pos.y).unwrap();
+    ///
+    /// assert_eq!(grid[v(5, 3)], 8);
+    ///
+    /// assert_eq!(
+    ///     Grid::try_from_fn(0, 0, |pos| pos.x),
+    ///     Err(GridError::NonPositiveDimensions {
+    ///         width: 0,
+    ///         height: 0
+    ///     })
+    /// );
+    /// ```
+    pub fn try_from_fn<F>(width: i64, height: i64, mut f: F) -> Result<Self, GridError>
+    where
+        F: FnMut(Vector) -> T,
+    {
+        let mut raw = Vec::with_capacity(size(width, height)?);
+        for y in 0..height {
+            for x in 0..width {
+                raw.push(f(Vector::new(x, y)));
+            }
+        }
+        Ok(Self {
+            raw,
+            dim: Vector::new(width, height),
+        })
+    }
+
+    /// Constructs a new `Grid<T>` with the dimensions `dim`, computing all initial values from the closure `f` which maps each position to a value.
+    ///
+    /// Equivalent to [`Grid::from_fn`] with the coordinates of `dim` as the width and height.
+    ///
+    /// Panics if the dimensions are not positive or too large.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::from_fn(4, 3, |pos| pos.x * pos.y);
+    /// let doubled = Grid::from_fn_with_dim(grid.dim(), |pos| grid[pos] * 2);
+    ///
+    /// assert_eq!(doubled.dim(), v(4, 3));
+    /// assert_eq!(doubled[v(3, 2)], 12);
+    /// ```
+    #[track_caller]
+    pub fn from_fn_with_dim<F>(dim: Vector, f: F) -> Self
+    where
+        F: FnMut(Vector) -> T,
+    {
+        Self::from_fn(dim.x, dim.y, f)
+    }
+
+    /// Constructs a new `Grid<T>` with the given dimensions and values computed by an iterator in row-major order.
+    ///
+    /// Panics if the dimensions are not positive or too large.
+    ///
+    /// Panics if the iterator runs out before the grid is filled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::from_iter(2, 3, [1, 2, 3, 4, 5, 6]);
+    ///
+    /// assert_eq!(grid[v(1, 0)], 2);
+    /// assert_eq!(grid[v(0, 2)], 5);
+    /// assert_eq!(grid[v(1, 2)], 6);
+    /// ```
+    #[track_caller]
+    pub fn from_iter<I>(width: i64, height: i64, values: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let size = unwrap(size(width, height));
+        let mut raw = Vec::with_capacity(size);
+        let mut values = values.into_iter();
+        for _ in 0..size {
+            raw.push(values.next().expect("iterator too short"));
+        }
+        Self {
+            raw,
+            dim: Vector::new(width, height),
+        }
+    }
+
+    /// Constructs a new `Grid<T>` with the given dimensions from a `Vec<T>` of values in row-major order, without copying the values.
+    ///
+    /// Returns an error if the dimensions are not positive or too large, or if the length of `values` is not `width * height`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+    ///
+    /// assert_eq!(grid[v(1, 0)], 2);
+    /// assert_eq!(grid[v(0, 2)], 5);
+    ///
+    /// assert_eq!(
+    ///     Grid::from_vec(2, 3, vec![1, 2, 3]),
+    ///     Err(GridError::LengthMismatch {
+    ///         expected: 6,
+    ///         found: 3
+    ///     })
+    /// );
+    /// ```
+    pub fn from_vec(width: i64, height: i64, values: Vec<T>) -> Result<Self, GridError> {
+        let size = size(width, height)?;
+        if values.len() != size {
+            return Err(GridError::LengthMismatch {
+                expected: size,
+                found: values.len(),
+            });
+        }
+        Ok(Self {
+            raw: values,
+            dim: Vector::new(width, height),
+        })
+    }
+
+    /// Constructs a new `Grid<T>` from an iterator of iterators, where each inner iterator defines a row.
+    ///
+    /// If there are no values, whether because there are no rows or because every row is empty, the grid is empty
+    /// and has dimensions `(0, 0)`.
+    ///
+    /// Panics if not all inner iterators are the same length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let values = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// ];
+    ///
+    /// let grid: Grid<u8> = Grid::from_nested_iter(values);
+    ///
+    /// assert_eq!(grid.width(), 3);
+    /// assert_eq!(grid.height(), 2);
+    /// assert_eq!(grid[v(2, 1)], 6);
+    ///
+    /// let empty: Grid<u8> = Grid::from_nested_iter([[], [], []]);
+    ///
+    /// assert_eq!(empty.dim(), v(0, 0));
+    /// ```
+    #[track_caller]
+    pub fn from_nested_iter<I, J>(values: I) -> Self
+    where
+        I: IntoIterator<Item = J>,
+        J: IntoIterator<Item = T>,
+    {
+        let mut values = values.into_iter();
+        let mut raw = Vec::new();
+        let Some(first) = values.next() else {
+            return Self {
+                raw,
+                dim: Vector::new(0, 0),
+            };
+        };
+        for value in first.into_iter() {
+            raw.push(value);
+        }
+        let width = raw.len() as i64;
+        let mut height = 1;
+        for inner in values {
+            height += 1;
+            let mut count = 0;
+            for value in inner.into_iter() {
+                count += 1;
+                raw.push(value);
+            }
+            if count != width {
+                panic!("not all inner iterators are the same length");
+            }
+        }
+        if raw.is_empty() {
+            height = 0;
+        }
+        Self {
+            raw,
+            dim: Vector::new(width, height),
+        }
+    }
+
+    /// Constructs a new `Grid<T>` from an iterator of rows, where each row is an iterator over the values in that row.
+    ///
+    /// The width of the grid is the length of the first row, and the height is the number of rows.
+    ///
+    /// Returns an error if there are no rows, the first row is empty, or not all rows are the same length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    ///
+    /// let grid: Grid<u8> = Grid::from_rows(rows).unwrap();
+    ///
+    /// assert_eq!(grid.dim(), v(3, 2));
+    /// assert_eq!(grid[v(2, 1)], 6);
+    ///
+    /// assert_eq!(
+    ///     Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8]]),
+    ///     Err(GridError::RaggedRows {
+    ///         expected: 3,
+    ///         found: 2,
+    ///         row: 2
+    ///     })
+    /// );
+    /// assert_eq!(
+    ///     Grid::<u8>::from_rows(Vec::<Vec<u8>>::new()),
+    ///     Err(GridError::NonPositiveDimensions {
+    ///         width: 0,
+    ///         height: 0
+    ///     })
+    /// );
+    /// ```
+    pub fn from_rows<I, R>(rows: I) -> Result<Self, GridError>
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+    {
+        let mut raw = Vec::new();
+        let mut width = 0;
+        let mut height = 0;
+        for row in rows {
+            let len = push_row(&mut raw, row);
+            if height == 0 {
+                width = len;
+            } else if len != width {
+                return Err(GridError::RaggedRows {
+                    expected: width,
+                    found: len,
+                    row: height,
+                });
+            }
+            height += 1;
+        }
+        size(width, height)?;
+        Ok(Self {
+            raw,
+            dim: Vector::new(width, height),
+        })
+    }
+
+    /// Constructs a new `Grid<T>` from a string with one row per line, mapping each character to a value with the closure `f`.
+    ///
+    /// Lines are split as by [`str::lines`], so both `\n` and `\r\n` line endings are accepted and a single trailing line ending is ignored.
+    ///
+    /// Returns [`GridError::EmptyInput`] if the string contains no non-empty lines.
+    /// Otherwise returns an error if the first line is empty, or [`GridError::RaggedRows`] for the first line
+    /// that does not have the same number of characters as the first line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<bool> = Grid::from_str_with("#..\n.#.\r\n..#\n", |c| c == '#').unwrap();
+    ///
+    /// assert_eq!(grid.dim(), v(3, 3));
+    /// assert_eq!(grid[v(1, 1)], true);
+    /// assert_eq!(grid[v(2, 1)], false);
+    ///
+    /// assert_eq!(
+    ///     Grid::from_str_with("#..\n.#\n#..", |c| c == '#'),
+    ///     Err(GridError::RaggedRows {
+    ///         expected: 3,
+    ///         found: 2,
+    ///         row: 1
+    ///     })
+    /// );
+    /// assert_eq!(Grid::from_str_with("\n\n", |c| c == '#'), Err(GridError::EmptyInput));
+    /// assert_eq!(Grid::from_str_with("", |c| c == '#'), Err(GridError::EmptyInput));
+    /// ```
+    pub fn from_str_with<F>(s: &str, f: F) -> Result<Self, GridError>
+    where
+        F: FnMut(char) -> T,
+    {
+        if s.lines().all(str::is_empty) {
+            return Err(GridError::EmptyInput);
+        }
+        Ok(Grid::from_rows(s.lines().map(str::chars))?.map_into(f))
+    }
+
+    /// Constructs a new `Grid<T>` from a string with one row per line, converting each character to a value with [`From<char>`].
+    ///
+    /// See [`Grid::from_str_with`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<char> = Grid::from_char_str("ab\ncd").unwrap();
+    ///
+    /// assert_eq!(grid[v(1, 0)], 'b');
+    /// assert_eq!(grid[v(0, 1)], 'c');
+    /// ```
+    pub fn from_char_str(s: &str) -> Result<Self, GridError>
+    where
+        T: From<char>,
+    {
+        Self::from_str_with(s, T::from)
+    }
+
+    /// Constructs a new `Grid<T>` from comma-separated values with one row per line, converting each field with `parse`.
+    ///
+    /// Fields are split on every comma, with no support for quoting or escaping, and there is no header row.
+    /// Lines are split as by [`str::lines`], so a single trailing line ending is ignored.
+    ///
+    /// Returns [`GridError::EmptyInput`] if the string contains no non-empty lines, or [`GridError::RaggedRows`] for
+    /// the first line that does not have the same number of fields as the first line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::from_csv("1,2,3\n4,5,-6\n", |s| s.parse().unwrap()).unwrap();
+    ///
+    /// assert_eq!(grid, Grid::from_nested_iter([[1, 2, 3], [4, 5, -6]]));
+    /// assert_eq!(Grid::from_csv(&grid.to_csv(), |s| s.parse().unwrap()), Ok(grid));
+    ///
+    /// assert_eq!(
+    ///     Grid::from_csv("1,2\n3", |s| s.to_string()),
+    ///     Err(GridError::RaggedRows {
+    ///         expected: 2,
+    ///         found: 1,
+    ///         row: 1
+    ///     })
+    /// );
+    /// assert_eq!(Grid::from_csv("\n", |s| s.to_string()), Err(GridError::EmptyInput));
+    /// ```
+    pub fn from_csv<F>(s: &str, parse: F) -> Result<Self, GridError>
+    where
+        F: FnMut(&str) -> T,
+    {
+        if s.lines().all(str::is_empty) {
+            return Err(GridError::EmptyInput);
+        }
+        Ok(Grid::from_rows(s.lines().map(|line| line.split(',')))?.map_into(parse))
+    }
+
+    /// Renders the grid to a string, converting each value with `f` and joining the values in each row with `col_sep`
+    /// and the rows with `row_sep`.
+    ///
+    /// Unlike the [`Debug`](fmt::Debug) implementation, no dimensions header or padding is added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::from_nested_iter([[1, 20, 3], [4, 5, -6]]);
+    ///
+    /// assert_eq!(grid.to_string_with(|n| n.to_string(), ",", "\n"), "1,20,3\n4,5,-6");
+    /// assert_eq!(
+    ///     grid.to_string_with(|&n| if n > 3 { "#" } else { "." }.to_string(), "", "\n"),
+    ///     ".#.\n##."
+    /// );
+    /// assert_eq!(grid.to_string_with(|n| format!("{n:>2}"), " ", " | "), " 1 20  3 |  4  5 -6");
+    /// ```
+    pub fn to_string_with<F>(&self, mut f: F, col_sep: &str, row_sep: &str) -> String
+    where
+        F: FnMut(&T) -> String,
+    {
+        let mut out = String::new();
+        for (y, row) in self.rows().enumerate() {
+            if y != 0 {
+                out.push_str(row_sep);
+            }
+            for (x, value) in row.iter().enumerate() {
+                if x != 0 {
+                    out.push_str(col_sep);
+                }
+                out.push_str(&f(value));
+            }
+        }
+        out
+    }
+
+    /// Renders the grid as comma-separated values with one row per line and no header row.
+    ///
+    /// Fields are not quoted or escaped, so the [`Display`](fmt::Display) output of `T` must not contain commas or
+    /// line breaks for the result to be read back by [`Grid::from_csv`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<f64> = Grid::from_nested_iter([[0.5, 1.0], [-2.25, 3.0]]);
+    ///
+    /// assert_eq!(grid.to_csv(), "0.5,1\n-2.25,3");
+    /// ```
+    pub fn to_csv(&self) -> String
+    where
+        T: fmt::Display,
+    {
+        self.to_string_with(ToString::to_string, ",", "\n")
+    }
+
+    /// Returns the width of the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::new(8, 10, 9);
+    ///
+    /// assert_eq!(grid.width(), 8);
+    /// ```
+    #[inline]
+    pub fn width(&self) -> i64 {
+        self.dim.x
+    }
+
+    /// Returns the height of the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::new(8, 10, 10);
+    ///
+    /// assert_eq!(grid.height(), 10);
+    /// ```
+    #[inline]
+    pub fn height(&self) -> i64 {
+        self.dim.y
+    }
+
+    /// Returns the dimensions of the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::new(8, 10, 11);
+    ///
+    /// assert_eq!(grid.dim(), v(8, 10));
+    /// ```
+    #[inline]
+    pub fn dim(&self) -> Vector {
+        self.dim
+    }
+
+    /// Returns `true` if `other` has the same dimensions as `self`, regardless of the types of their values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid_a: Grid<u8> = Grid::new(4, 3, 0);
+    /// let grid_b: Grid<char> = Grid::new(4, 3, '.');
+    /// let grid_c: Grid<char> = Grid::new(3, 4, '.');
+    ///
+    /// assert!(grid_a.same_dim(&grid_b));
+    /// assert!(!grid_a.same_dim(&grid_c));
+    /// ```
+    #[inline]
+    pub fn same_dim<U>(&self, other: &Grid<U>) -> bool {
+        self.dim == other.dim
+    }
+
+    /// Returns the number of values in the grid, equal to `width * height`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::new(8, 10, 0);
+    ///
+    /// assert_eq!(grid.len(), 80);
+    /// assert_eq!(grid.len(), grid.iter().count());
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.raw.len()
+    }
+
+    /// Returns `true` if the grid contains no values.
+    ///
+    /// Every other constructor produces a grid with positive dimensions, and no method removes its last row or column,
+    /// so this is only `true` for a grid built by [`Grid::from_nested_iter`] from no rows or from rows that are all empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::new(3, 1, 0);
+    ///
+    /// assert!(!grid.is_empty());
+    ///
+    /// let grid: Grid<u8> = Grid::from_nested_iter(Vec::<Vec<u8>>::new());
+    ///
+    /// assert!(grid.is_empty());
+    /// assert_eq!(grid.dim(), v(0, 0));
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.raw.is_empty()
+    }
+
+    /// Returns the values of the grid as a `Vec<T>` in row-major order, without copying the values.
+    ///
+    /// This is the inverse of [`Grid::from_vec`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// let dim = grid.dim();
+    ///
+    /// let values = grid.into_vec();
+    ///
+    /// assert_eq!(values, vec![1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(Grid::from_vec(dim.x, dim.y, values).unwrap()[v(1, 2)], 6);
+    /// ```
+    #[inline]
+    pub fn into_vec(self) -> Vec<T> {
+        self.raw
+    }
+
+    /// Returns the number of values the grid can hold without reallocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::new(4, 3, 0);
+    ///
+    /// assert!(grid.capacity() >= 12);
+    /// ```
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.raw.capacity()
+    }
+
+    /// Reserves capacity for at least `additional` more rows, so that appending them with [`Grid::insert_row`] does not reallocate.
+    ///
+    /// Does nothing if `additional` is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<u32> = Grid::new(100, 1, 0);
+    ///
+    /// grid.reserve_rows(999);
+    /// let capacity = grid.capacity();
+    ///
+    /// for y in 1..1000 {
+    ///     grid.insert_row(y, y as u32);
+    /// }
+    ///
+    /// assert_eq!(grid.dim(), v(100, 1000));
+    /// assert_eq!(grid.capacity(), capacity);
+    /// assert_eq!(grid[v(42, 999)], 999);
+    /// ```
+    pub fn reserve_rows(&mut self, additional: i64) {
+        if additional > 0 {
+            let additional = (additional as usize).saturating_mul(self.width() as usize);
+            self.raw.reserve(additional);
+        }
+    }
+
+    /// Shrinks the capacity of the grid as much as possible, releasing capacity reserved by [`Grid::reserve_rows`] or left by removed rows and columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<u8> = Grid::new(4, 3, 0);
+    ///
+    /// grid.reserve_rows(100);
+    /// grid.shrink_to_fit();
+    ///
+    /// assert!(grid.capacity() < 400);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.raw.shrink_to_fit();
+    }
+
+    /// Returns a raw pointer to the first value of the grid.
+    ///
+    /// The values are stored contiguously in row-major order, so the value at `pos` is at an offset of
+    /// `pos.x + pos.y * width` from this pointer, and there are `width * height` values in total.
+    /// The pointer is valid for as long as the grid is neither dropped nor modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::from_fn(4, 3, |pos| pos.x * 10 + pos.y);
+    ///
+    /// let ptr = grid.as_ptr();
+    ///
+    /// assert_eq!(unsafe { *ptr.add(1 + 2 * 4) }, 12);
+    /// ```
+    #[inline]
+    pub fn as_ptr(&self) -> *const T {
+        self.raw.as_ptr()
+    }
+
+    /// Returns a raw mutable pointer to the first value of the grid.
+    ///
+    /// The layout is the same as for [`Grid::as_ptr`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<u8> = Grid::new(4, 3, 0);
+    ///
+    /// let ptr = grid.as_mut_ptr();
+    ///
+    /// unsafe {
+    ///     *ptr.add(3 + 1 * 4) = 7;
+    /// }
+    ///
+    /// assert_eq!(grid[v(3, 1)], 7);
+    /// ```
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.raw.as_mut_ptr()
+    }
+
+    /// Changes the dimensions of the grid without moving any values, so that the values keep their row-major order.
+    ///
+    /// Returns an error and leaves the grid unchanged if the new dimensions are not positive or too large,
+    /// or if they do not contain the same number of positions as the current dimensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<u8> = Grid::from_iter(6, 1, [1, 2, 3, 4, 5, 6]);
+    ///
+    /// assert_eq!(grid.reshape(v(2, 3)), Ok(()));
+    /// assert_eq!(grid, Grid::from_nested_iter([[1, 2], [3, 4], [5, 6]]));
+    ///
+    /// assert_eq!(
+    ///     grid.reshape(v(4, 2)),
+    ///     Err(GridError::LengthMismatch {
+    ///         expected: 8,
+    ///         found: 6
+    ///     })
+    /// );
+    /// assert_eq!(
+    ///     grid.reshape(v(-2, -3)),
+    ///     Err(GridError::NonPositiveDimensions {
+    ///         width: -2,
+    ///         height: -3
+    ///     })
+    /// );
+    /// assert_eq!(grid.dim(), v(2, 3));
+    /// ```
+    pub fn reshape(&mut self, new_dim: Vector) -> Result<(), GridError> {
+        let size = size(new_dim.x, new_dim.y)?;
+        if size != self.raw.len() {
+            return Err(GridError::LengthMismatch {
+                expected: size,
+                found: self.raw.len(),
+            });
+        }
+        self.dim = new_dim;
+        Ok(())
+    }
+
+    /// Returns a reference to the value at the given position of the grid, or `None` if out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<u8> = Grid::new(8, 10, 3);
+    ///
+    /// grid[v(1, 1)] = 4;
+    ///
+    /// assert_eq!(grid.get(v(5, 2)), Some(&3));
+    /// assert_eq!(grid.get(v(1, 1)), Some(&4));
+    /// assert_eq!(grid.get(v(8, 6)), None);
+    /// assert_eq!(grid.get(v(4, 10)), None);
+    /// assert_eq!(grid.get(v(-2, 3)), None);
+    /// ```
+    pub fn get(&self, pos: Vector) -> Option<&T> {
+        Some(&self.raw[self.get_index(pos)?])
+    }
+
+    /// Returns a mutable reference to the value at the given position of the grid, or `None` if out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<u8> = Grid::new(8, 10, 4);
+    ///
+    /// grid[v(5, 3)] = 2;
+    ///
+    /// assert_eq!(grid.get_mut(v(5, 3)), Some(&mut 2));
+    /// assert_eq!(grid.get_mut(v(0, 0)), Some(&mut 4));
+    /// assert_eq!(grid.get_mut(v(1, 10)), None);
+    /// assert_eq!(grid.get_mut(v(9, 7)), None);
+    /// assert_eq!(grid.get_mut(v(4, -1)), None);
+    /// ```
+    pub fn get_mut(&mut self, pos: Vector) -> Option<&mut T> {
+        let index = self.get_index(pos)?;
+        Some(&mut self.raw[index])
+    }
+
+    /// Returns a reference to the value at the given position of the grid.
+    ///
+    /// Returns [`GridError::OutOfBounds`] with the position and the dimensions of the grid if the position is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::new(8, 10, 4);
+    ///
+    /// assert_eq!(grid.try_get(v(5, 3)), Ok(&4));
+    /// assert_eq!(
+    ///     grid.try_get(v(9, 3)),
+    ///     Err(GridError::OutOfBounds {
+    ///         pos: v(9, 3),
+    ///         dim: v(8, 10)
+    ///     })
+    /// );
+    ///
+    /// fn corner_sum(grid: &Grid<u8>) -> Result<u8, GridError> {
+    ///     Ok(grid.try_get(v(0, 0))? + grid.try_get(grid.dim() - v(1, 1))?)
+    /// }
+    ///
+    /// assert_eq!(corner_sum(&grid), Ok(8));
+    /// ```
+    pub fn try_get(&self, pos: Vector) -> Result<&T, GridError> {
+        let dim = self.dim;
+        self.get(pos).ok_or(GridError::OutOfBounds { pos, dim })
+    }
+
+    /// Returns a mutable reference to the value at the given position of the grid.
+    ///
+    /// Returns [`GridError::OutOfBounds`] with the position and the dimensions of the grid if the position is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<u8> = Grid::new(8, 10, 4);
+    ///
+    /// *grid.try_get_mut(v(5, 3)).unwrap() = 2;
+    ///
+    /// assert_eq!(grid[v(5, 3)], 2);
+    /// assert_eq!(
+    ///     grid.try_get_mut(v(-1, 0)).unwrap_err().to_string(),
+    ///     "position out of bounds: the dimensions are (8, 10) but the position is (-1, 0)"
+    /// );
+    /// ```
+    pub fn try_get_mut(&mut self, pos: Vector) -> Result<&mut T, GridError> {
+        let dim = self.dim;
+        self.get_mut(pos).ok_or(GridError::OutOfBounds { pos, dim })
+    }
+
+    /// Returns a reference to the value at the given position of the grid, without checking that the position is in bounds.
+    ///
+    /// For a safe alternative see [`Grid::get`].
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with a position that is out of bounds is undefined behaviour, even if the resulting reference is not used.
+    /// A position is in bounds if [`Grid::in_bounds`] returns `true`. This is checked with a debug assertion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::from_fn(3, 2, |pos| pos.x + pos.y * 3);
+    ///
+    /// let mut sum = 0;
+    /// for y in 0..grid.height() {
+    ///     for x in 0..grid.width() {
+    ///         sum += unsafe { grid.get_unchecked(v(x, y)) };
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(sum, 15);
+    /// ```
+    #[inline]
+    pub unsafe fn get_unchecked(&self, pos: Vector) -> &T {
+        debug_assert!(
+            self.in_bounds(pos),
+            "position out of bounds: the dimensions are {} but the position is {pos}",
+            self.dim
+        );
+        self.raw
+            .get_unchecked(pos.x as usize + pos.y as usize * self.width() as usize)
+    }
+
+    /// Returns a mutable reference to the value at the given position of the grid, without checking that the position is in bounds.
+    ///
+    /// For a safe alternative see [`Grid::get_mut`].
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with a position that is out of bounds is undefined behaviour, even if the resulting reference is not used.
+    /// A position is in bounds if [`Grid::in_bounds`] returns `true`. This is checked with a debug assertion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<u8> = Grid::new(3, 2, 0);
+    ///
+    /// unsafe {
+    ///     *grid.get_unchecked_mut(v(2, 1)) = 5;
+    /// }
+    ///
+    /// assert_eq!(grid[v(2, 1)], 5);
+    /// ```
+    #[inline]
+    pub unsafe fn get_unchecked_mut(&mut self, pos: Vector) -> &mut T {
+        debug_assert!(
+            self.in_bounds(pos),
+            "position out of bounds: the dimensions are {} but the position is {pos}",
+            self.dim
+        );
+        let index = pos.x as usize + pos.y as usize * self.width() as usize;
+        self.raw.get_unchecked_mut(index)
+    }
+
+    /// Returns mutable references to the values at two different positions of the grid,
+    /// or `None` if either position is out of bounds or the positions are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<i64> = Grid::from_fn(3, 2, |pos| pos.x + pos.y * 3);
+    ///
+    /// let (a, b) = grid.get_two_mut(v(2, 1), v(0, 0)).unwrap();
+    /// *a += *b + 10;
+    /// *b = 7;
+    ///
+    /// assert_eq!(grid[v(2, 1)], 15);
+    /// assert_eq!(grid[v(0, 0)], 7);
+    ///
+    /// assert!(grid.get_two_mut(v(1, 1), v(1, 1)).is_none());
+    /// assert!(grid.get_two_mut(v(1, 1), v(3, 1)).is_none());
+    /// ```
+    pub fn get_two_mut(&mut self, a: Vector, b: Vector) -> Option<(&mut T, &mut T)> {
+        let a = self.get_index(a)?;
+        let b = self.get_index(b)?;
+        if a < b {
+            let (left, right) = self.raw.split_at_mut(b);
+            Some((&mut left[a], &mut right[0]))
+        } else if b < a {
+            let (left, right) = self.raw.split_at_mut(a);
+            Some((&mut right[0], &mut left[b]))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the value at the given position along with mutable references to its four
+    /// orthogonal neighbours, or `None` if the position is out of bounds.
+    ///
+    /// The neighbours are in the same order as the offsets in [`ORTHOGONAL`](crate::prelude::ORTHOGONAL):
+    /// east, north, west, south, with `None` for those out of bounds. Neighbours do not wrap around the edges, so
+    /// every returned reference points to a distinct value and they can all be used at once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<i64> = Grid::from_fn(3, 3, |pos| pos.x + pos.y * 3);
+    ///
+    /// let (center, neighbors) = grid.cell_and_orthogonal_mut(v(1, 1)).unwrap();
+    ///
+    /// // spread the centre value evenly to its neighbours
+    /// let share = *center / 4;
+    /// for neighbor in neighbors.into_iter().flatten() {
+    ///     *neighbor += share;
+    /// }
+    /// *center = 0;
+    ///
+    /// assert_eq!(grid, Grid::from_nested_iter([[0, 2, 2], [4, 0, 6], [6, 8, 8]]));
+    ///
+    /// let (corner, neighbors) = grid.cell_and_orthogonal_mut(v(2, 0)).unwrap();
+    ///
+    /// assert_eq!(*corner, 2);
+    /// assert_eq!(neighbors.map(|n| n.map(|n| *n)), [None, None, Some(2), Some(6)]);
+    ///
+    /// assert!(grid.cell_and_orthogonal_mut(v(3, 0)).is_none());
+    /// ```
+    pub fn cell_and_orthogonal_mut(
+        &mut self,
+        pos: Vector,
+    ) -> Option<(&mut T, [Option<&mut T>; 4])> {
+        let index = self.get_index(pos)?;
+        let width = self.width() as usize;
+        let (before, rest) = self.raw.split_at_mut(index);
+        let (center, after) = rest.split_first_mut()?;
+        let (west, before) = if pos.x > 0 {
+            let (west, before) = before.split_last_mut()?;
+            (Some(west), before)
+        } else {
+            (None, before)
+        };
+        let (east, after) = if pos.x < self.dim.x - 1 {
+            let (east, after) = after.split_first_mut()?;
+            (Some(east), after)
+        } else {
+            (None, after)
+        };
+        let north = index.checked_sub(width).and_then(|i| before.get_mut(i));
+        let south = after.get_mut(width - 1 - east.is_some() as usize);
+        Some((center, [east, north, west, south]))
+    }
+
+    /// Returns the row at the given `y` coordinate as a slice, or `None` if out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::from_fn(3, 2, |pos| pos.x + pos.y * 3);
+    ///
+    /// assert_eq!(grid.get_row(0), Some(&[0, 1, 2][..]));
+    /// assert_eq!(grid.get_row(1), Some(&[3, 4, 5][..]));
+    /// assert_eq!(grid.get_row(2), None);
+    /// assert_eq!(grid.get_row(-1), None);
+    /// ```
+    pub fn get_row(&self, y: i64) -> Option<&[T]> {
+        let range = self.row_range(y)?;
+        Some(&self.raw[range])
+    }
+
+    /// Returns the row at the given `y` coordinate as a mutable slice, or `None` if out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<u8> = Grid::new(3, 2, 0);
+    ///
+    /// grid.get_row_mut(1).unwrap().copy_from_slice(&[4, 5, 6]);
+    ///
+    /// assert_eq!(grid[v(0, 1)], 4);
+    /// assert_eq!(grid[v(2, 1)], 6);
+    /// assert_eq!(grid.get_row_mut(2), None);
+    /// ```
+    pub fn get_row_mut(&mut self, y: i64) -> Option<&mut [T]> {
+        let range = self.row_range(y)?;
+        Some(&mut self.raw[range])
+    }
+
+    /// Returns an iterator over references to the values in the column at the given `x` coordinate, or `None` if out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::from_fn(3, 2, |pos| pos.x + pos.y * 3);
+    ///
+    /// assert!(grid.get_col(1).unwrap().eq(&[1, 4]));
+    /// assert!(grid.get_col(3).is_none());
+    /// assert!(grid.get_col(-1).is_none());
+    /// ```
+    pub fn get_col(&self, x: i64) -> Option<Column<'_, T>> {
+        (0..self.width()).contains(&x).then(|| {
+            Column::new(
+                &self.raw[x as usize..],
+                self.width() as usize,
+                self.height() as usize,
+            )
+        })
+    }
+
+    fn row_range(&self, y: i64) -> Option<Range<usize>> {
+        let width = self.width() as usize;
+        (0..self.height())
+            .contains(&y)
+            .then(|| (y as usize * width)..((y as usize + 1) * width))
+    }
+
+    /// Sets the value at the given position of the grid.
+    ///
+    /// Returns the old value at that position, or `None` if out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<u8> = Grid::new(8, 10, 5);
+    ///
+    /// assert_eq!(grid.set(v(2, 3), 7), Some(5));
+    /// assert_eq!(grid.set(v(9, 12), 1), None);
+    /// assert_eq!(grid.set(v(-4, -7), 3), None);
+    ///
+    /// assert_eq!(grid[v(2, 3)], 7);
+    /// ```
+    pub fn set(&mut self, pos: Vector, value: T) -> Option<T> {
+        Some(std::mem::replace(self.get_mut(pos)?, value))
+    }
+
+    /// Swaps the values at two positions of the grid.
+    ///
+    /// Panics if either position is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<i64> = Grid::from_fn(3, 2, |pos| pos.x + pos.y * 3);
+    ///
+    /// grid.swap(v(0, 0), v(2, 1));
+    ///
+    /// assert_eq!(grid[v(0, 0)], 5);
+    /// assert_eq!(grid[v(2, 1)], 0);
+    ///
+    /// grid.swap(v(1, 1), v(1, 1));
+    ///
+    /// assert_eq!(grid[v(1, 1)], 4);
+    /// ```
+    #[track_caller]
+    pub fn swap(&mut self, a: Vector, b: Vector) {
+        let dim = self.dim;
+        match (self.get_index(a), self.get_index(b)) {
+            (Some(a), Some(b)) => self.raw.swap(a, b),
+            (None, _) => out_of_bounds(a, dim),
+            (_, None) => out_of_bounds(b, dim),
+        }
+    }
+
+    /// Swaps the values at two positions of the grid.
+    ///
+    /// Returns `false` and leaves the grid unchanged if either position is out of bounds, or `true` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<i64> = Grid::from_fn(3, 2, |pos| pos.x + pos.y * 3);
+    ///
+    /// assert_eq!(grid.try_swap(v(0, 0), v(2, 1)), true);
+    /// assert_eq!(grid.try_swap(v(0, 0), v(3, 1)), false);
+    ///
+    /// assert_eq!(grid[v(0, 0)], 5);
+    /// ```
+    pub fn try_swap(&mut self, a: Vector, b: Vector) -> bool {
+        let (Some(a), Some(b)) = (self.get_index(a), self.get_index(b)) else {
+            return false;
+        };
+        self.raw.swap(a, b);
+        true
+    }
+
+    /// Swaps the rows at the given `y` coordinates.
+    ///
+    /// Panics if either row is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<u8> = Grid::from_nested_iter([[1, 2], [3, 4], [5, 6]]);
+    ///
+    /// grid.swap_rows(0, 2);
+    ///
+    /// assert_eq!(grid, Grid::from_nested_iter([[5, 6], [3, 4], [1, 2]]));
+    ///
+    /// grid.swap_rows(1, 1);
+    /// grid.swap_rows(2, 0);
+    ///
+    /// assert_eq!(grid, Grid::from_nested_iter([[1, 2], [3, 4], [5, 6]]));
+    /// ```
+    #[track_caller]
+    pub fn swap_rows(&mut self, y0: i64, y1: i64) {
+        let height = self.height();
+        let (Some(a), Some(b)) = (self.row_range(y0), self.row_range(y1)) else {
+            panic!("row out of bounds: the height is {height} but the rows are {y0} and {y1}");
+        };
+        let (a, b) = if a.start <= b.start { (a, b) } else { (b, a) };
+        if a == b {
+            return;
+        }
+        let (top, bottom) = self.raw.split_at_mut(b.start);
+        top[a].swap_with_slice(&mut bottom[..b.end - b.start]);
+    }
+
+    /// Swaps the columns at the given `x` coordinates.
+    ///
+    /// Panics if either column is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<u8> = Grid::from_nested_iter([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// grid.swap_cols(0, 1);
+    ///
+    /// assert_eq!(grid, Grid::from_nested_iter([[2, 1, 3], [5, 4, 6]]));
+    ///
+    /// grid.swap_cols(2, 2);
+    /// grid.swap_cols(1, 0);
+    ///
+    /// assert_eq!(grid, Grid::from_nested_iter([[1, 2, 3], [4, 5, 6]]));
+    /// ```
+    #[track_caller]
+    pub fn swap_cols(&mut self, x0: i64, x1: i64) {
+        let width = self.width();
+        if !(0..width).contains(&x0) || !(0..width).contains(&x1) {
+            panic!("column out of bounds: the width is {width} but the columns are {x0} and {x1}");
+        }
+        for y in 0..self.height() {
+            self.raw
+                .swap((x0 + y * width) as usize, (x1 + y * width) as usize);
+        }
+    }
+
+    /// Removes the row at the given `y` coordinate and returns its values, moving all rows below it up by one.
+    ///
+    /// Returns `None` and leaves the grid unchanged if `y` is out of bounds or the grid has only one row,
+    /// since a grid must have positive dimensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<u8> = Grid::from_nested_iter([[1, 2], [3, 4], [5, 6]]);
+    ///
+    /// assert_eq!(grid.remove_row(1), Some(vec![3, 4]));
+    /// assert_eq!(grid.remove_row(2), None);
+    ///
+    /// assert_eq!(grid, Grid::from_nested_iter([[1, 2], [5, 6]]));
+    ///
+    /// let mut grid: Grid<u8> = Grid::new(3, 1, 0);
+    ///
+    /// assert_eq!(grid.remove_row(0), None);
+    /// assert_eq!(grid.dim(), v(3, 1));
+    /// ```
+    pub fn remove_row(&mut self, y: i64) -> Option<Vec<T>> {
+        if self.height() == 1 {
+            return None;
+        }
+        let range = self.row_range(y)?;
+        let row = self.raw.drain(range).collect();
+        self.dim.y -= 1;
+        Some(row)
+    }
+
+    /// Removes the column at the given `x` coordinate and returns its values, moving all columns to the right of it left by one.
+    ///
+    /// Returns `None` and leaves the grid unchanged if `x` is out of bounds or the grid has only one column,
+    /// since a grid must have positive dimensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<u8> = Grid::from_nested_iter([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// assert_eq!(grid.remove_col(0), Some(vec![1, 4]));
+    /// assert_eq!(grid.remove_col(-1), None);
+    ///
+    /// assert_eq!(grid, Grid::from_nested_iter([[2, 3], [5, 6]]));
+    ///
+    /// let mut grid: Grid<u8> = Grid::new(1, 4, 0);
+    ///
+    /// assert_eq!(grid.remove_col(0), None);
+    /// assert_eq!(grid.dim(), v(1, 4));
+    /// ```
+    pub fn remove_col(&mut self, x: i64) -> Option<Vec<T>> {
+        let (width, height) = (self.width(), self.height());
+        if width == 1 || !(0..width).contains(&x) {
+            return None;
+        }
+        let mut old = std::mem::take(&mut self.raw).into_iter();
+        let mut raw = Vec::with_capacity(((width - 1) * height) as usize);
+        let mut col = Vec::with_capacity(height as usize);
+        for _ in 0..height {
+            raw.extend(old.by_ref().take(x as usize));
+            col.extend(old.next());
+            raw.extend(old.by_ref().take((width - x - 1) as usize));
+        }
+        self.raw = raw;
+        self.dim.x -= 1;
+        Some(col)
+    }
+
+    /// Returns `true` if the given position is within the bounds of the grid, or `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::new(15, 14, 11);
+    ///
+    /// assert_eq!(grid.in_bounds(v(0, 0)), true);
+    /// assert_eq!(grid.in_bounds(v(10, 4)), true);
+    /// assert_eq!(grid.in_bounds(v(15, 2)), false);
+    /// assert_eq!(grid.in_bounds(v(3, 17)), false);
+    /// assert_eq!(grid.in_bounds(v(-1, 5)), false);
+    /// assert_eq!(grid.in_bounds(v(-15, -14)), false);
+    /// ```
+    pub fn in_bounds(&self, pos: Vector) -> bool {
+        (0..self.width()).contains(&pos.x) && (0..self.height()).contains(&pos.y)
+    }
+
+    /// Returns `pos + offset` if it is within the bounds of the grid, or `None` otherwise, including if the addition
+    /// overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::new(3, 2, 0);
+    ///
+    /// assert_eq!(grid.offset(v(1, 0), SE), Some(v(2, 1)));
+    /// assert_eq!(grid.offset(v(2, 1), ZERO), Some(v(2, 1)));
+    ///
+    /// assert_eq!(grid.offset(v(0, 1), WEST), None);
+    /// assert_eq!(grid.offset(v(2, 0), EAST), None);
+    /// assert_eq!(grid.offset(v(1, 0), NORTH), None);
+    /// assert_eq!(grid.offset(v(1, 1), SOUTH), None);
+    /// assert_eq!(grid.offset(v(i64::MAX, 0), EAST), None);
+    /// ```
+    pub fn offset(&self, pos: Vector, offset: Vector) -> Option<Vector> {
+        pos.checked_add(offset).filter(|&pos| self.in_bounds(pos))
+    }
+
+    /// Returns references to the eight values adjacent to the given position, or `None` for those out of bounds.
+    ///
+    /// The values are in the same order as the offsets in [`ADJACENT`](crate::prelude::ADJACENT):
+    /// east, north-east, north, north-west, west, south-west, south, south-east.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid = Grid::from_nested_iter([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// assert_eq!(
+    ///     grid.adjacent_array(v(0, 1)),
+    ///     [Some(&5), Some(&2), Some(&1), None, None, None, None, None]
+    /// );
+    ///
+    /// let alive = grid.adjacent_array(v(1, 0)).iter().flatten().filter(|&&&n| n % 2 == 1).count();
+    ///
+    /// assert_eq!(alive, 3);
+    /// ```
+    pub fn adjacent_array(&self, pos: Vector) -> [Option<&T>; 8] {
+        ADJACENT.map(|offset| self.get(pos + offset))
+    }
+
+    /// Returns references to the four values orthogonally adjacent to the given position, or `None` for those out of bounds.
+    ///
+    /// The values are in the same order as the offsets in [`ORTHOGONAL`](crate::prelude::ORTHOGONAL):
+    /// east, north, west, south.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid = Grid::from_nested_iter([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// assert_eq!(grid.orthogonal_array(v(2, 1)), [None, Some(&3), Some(&5), None]);
+    /// assert_eq!(
+    ///     grid.orthogonal_array(v(1, 0)).map(|value| value.copied().unwrap_or(0)),
+    ///     [3, 0, 1, 5]
+    /// );
+    /// ```
+    pub fn orthogonal_array(&self, pos: Vector) -> [Option<&T>; 4] {
+        ORTHOGONAL.map(|offset| self.get(pos + offset))
+    }
+
+    /// Returns the positions and values of the four neighbours of the given position in the order of
+    /// [`ORTHOGONAL`](crate::prelude::ORTHOGONAL), wrapping around the edges as if the grid were a torus.
+    ///
+    /// The returned positions are the wrapped positions, so they are always in bounds.
+    ///
+    /// Panics if the grid is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::from_fn(4, 3, |pos| pos.x + pos.y * 4);
+    ///
+    /// assert_eq!(
+    ///     grid.orthogonal_neighbors_wrapping(v(0, 0)),
+    ///     [(v(1, 0), &1), (v(0, 2), &8), (v(3, 0), &3), (v(0, 1), &4)]
+    /// );
+    /// ```
+    #[track_caller]
+    pub fn orthogonal_neighbors_wrapping(&self, pos: Vector) -> [(Vector, &T); 4] {
+        ORTHOGONAL.map(|offset| self.wrapped_entry(pos + offset))
+    }
+
+    /// Returns the positions and values of the eight neighbours of the given position in the order of
+    /// [`ADJACENT`](crate::prelude::ADJACENT), wrapping around the edges as if the grid were a torus.
+    ///
+    /// The returned positions are the wrapped positions, so they are always in bounds.
+    ///
+    /// Panics if the grid is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::from_fn(4, 3, |pos| pos.x + pos.y * 4);
+    ///
+    /// let neighbors = grid.adjacent_neighbors_wrapping(v(3, 2));
+    ///
+    /// assert_eq!(neighbors[0], (v(0, 2), &8));
+    /// assert_eq!(neighbors[7], (v(0, 0), &0));
+    /// assert_eq!(neighbors.iter().map(|(_, &value)| value).sum::<i64>(), 40);
+    /// ```
+    #[track_caller]
+    pub fn adjacent_neighbors_wrapping(&self, pos: Vector) -> [(Vector, &T); 8] {
+        ADJACENT.map(|offset| self.wrapped_entry(pos + offset))
+    }
+
+    #[track_caller]
+    fn wrapped_entry(&self, pos: Vector) -> (Vector, &T) {
+        let pos = self.wrap(pos);
+        (pos, &self[pos])
+    }
+
+    /// Returns the position in the grid that the given position wraps around to, treating the grid as a torus.
+    ///
+    /// Panics if the grid is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::new(4, 3, 0);
+    ///
+    /// assert_eq!(grid.wrap(v(1, 2)), v(1, 2));
+    /// assert_eq!(grid.wrap(v(5, 3)), v(1, 0));
+    /// assert_eq!(grid.wrap(v(-1, -1)), v(3, 2));
+    /// assert_eq!(grid.wrap(v(-9, 7)), v(3, 1));
+    /// ```
+    #[track_caller]
+    pub fn wrap(&self, pos: Vector) -> Vector {
+        pos.rem_euclid(self.dim)
+    }
+
+    /// Returns a reference to the value at the given position of the grid, wrapping around the edges as if the grid were a torus.
+    ///
+    /// Panics if the grid is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::from_fn(4, 3, |pos| pos.x + pos.y * 4);
+    ///
+    /// assert_eq!(*grid.get_wrapping(v(-1, -1)), 11);
+    /// assert_eq!(*grid.get_wrapping(v(4, 0)), 0);
+    /// assert_eq!(*grid.get_wrapping(v(2, 1)), 6);
+    /// ```
+    #[track_caller]
+    pub fn get_wrapping(&self, pos: Vector) -> &T {
+        &self[self.wrap(pos)]
+    }
+
+    /// Returns a mutable reference to the value at the given position of the grid, wrapping around the edges as if the grid were a torus.
+    ///
+    /// Panics if the grid is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<u8> = Grid::new(4, 3, 0);
+    ///
+    /// *grid.get_wrapping_mut(v(-1, 3)) = 5;
+    ///
+    /// assert_eq!(grid[v(3, 0)], 5);
+    /// ```
+    #[track_caller]
+    pub fn get_wrapping_mut(&mut self, pos: Vector) -> &mut T {
+        let pos = self.wrap(pos);
+        &mut self[pos]
+    }
+
+    /// Returns the inclusive top-left and bottom-right corners of the grid, `(0, 0)` and `(width - 1, height - 1)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::new(4, 3, 0);
+    ///
+    /// let (min, max) = grid.bounds();
+    ///
+    /// assert_eq!((min, max), (v(0, 0), v(3, 2)));
+    /// assert_eq!(v(9, -2).clamp(min, max), v(3, 0));
+    /// ```
+    #[inline]
+    pub fn bounds(&self) -> (Vector, Vector) {
+        (Vector::new(0, 0), self.dim - Vector::new(1, 1))
+    }
+
+    /// Returns the position at the centre of the grid, `(width / 2, height / 2)`.
+    ///
+    /// The division rounds down, so for an even dimension this is the greater of the two middle coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// assert_eq!(Grid::new(5, 3, 0).center(), v(2, 1));
+    /// assert_eq!(Grid::new(4, 6, 0).center(), v(2, 3));
+    /// assert_eq!(Grid::new(1, 1, 0).center(), v(0, 0));
+    /// ```
+    #[inline]
+    pub fn center(&self) -> Vector {
+        self.dim / 2
+    }
+
+    /// Returns the position in the grid closest to the given position, by clamping each coordinate to the bounds of the grid.
+    ///
+    /// Panics if the grid is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::new(4, 3, 0);
+    ///
+    /// assert_eq!(grid.clamp_pos(v(1, 2)), v(1, 2));
+    /// assert_eq!(grid.clamp_pos(v(5, 1)), v(3, 1));
+    /// assert_eq!(grid.clamp_pos(v(-1, -7)), v(0, 0));
+    /// ```
+    #[track_caller]
+    pub fn clamp_pos(&self, pos: Vector) -> Vector {
+        let (min, max) = self.bounds();
+        pos.clamp(min, max)
+    }
+
+    /// Returns a reference to the value at the position in the grid closest to the given position, as given by [`Grid::clamp_pos`].
+    ///
+    /// Always returns a valid reference for a non-empty grid, so positions outside the grid take the value of the nearest edge.
+    ///
+    /// Panics if the grid is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::from_fn(4, 3, |pos| pos.x + pos.y * 4);
+    ///
+    /// assert_eq!(*grid.get_clamped(v(-1, -1)), 0);
+    /// assert_eq!(*grid.get_clamped(v(6, 1)), 7);
+    /// assert_eq!(*grid.get_clamped(v(2, 1)), 6);
+    /// ```
+    #[track_caller]
+    pub fn get_clamped(&self, pos: Vector) -> &T {
+        &self[self.clamp_pos(pos)]
+    }
+
+    /// Sets the value at the position in the grid closest to the given position, as given by [`Grid::clamp_pos`].
+    ///
+    /// Never fails for a non-empty grid: a position outside the grid writes to the nearest cell on its edge, so a
+    /// position far beyond a corner writes to that corner. See [`Grid::set`] to ignore out of bounds positions instead.
+    ///
+    /// Panics if the grid is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<u8> = Grid::new(3, 3, 0);
+    ///
+    /// grid.set_clamped(v(1, -5), 1);
+    /// grid.set_clamped(v(100, 100), 2);
+    /// grid.set_clamped(v(0, 1), 3);
+    ///
+    /// assert_eq!(grid, Grid::from_nested_iter([[0, 1, 0], [3, 0, 0], [0, 0, 2]]));
+    /// ```
+    #[track_caller]
+    pub fn set_clamped(&mut self, pos: Vector, value: T) {
+        let pos = self.clamp_pos(pos);
+        self[pos] = value;
+    }
+
+    fn get_index(&self, pos: Vector) -> Option<usize> {
+        self.in_bounds(pos)
+            .then(|| pos.x as usize + ((pos.y as usize) * (self.width() as usize)))
+    }
+
+    /// Returns the top-left position and the exclusive bottom-right position of the intersection of the grid with the
+    /// given rectangle, or `None` if they do not overlap.
+    fn clip_rect(&self, top_left: Vector, dim: Vector) -> Option<(Vector, Vector)> {
+        let start = top_left.max(Vector::new(0, 0));
+        let end = top_left.saturating_add(dim).min(self.dim);
+        (start.x < end.x && start.y < end.y).then_some((start, end))
+    }
+
+    fn contains_rect(&self, top_left: Vector, dim: Vector) -> bool {
+        dim.x > 0
+            && dim.y > 0
+            && self.in_bounds(top_left)
+            && dim.x <= self.width() - top_left.x
+            && dim.y <= self.height() - top_left.y
+    }
+
+    /// Maps the values of an existing grid to create a new grid with the same dimensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid_a: Grid<u8> = Grid::new(15, 14, 11);
+    ///
+    /// let grid_b = grid_a.map(|value| *value + 2);
+    ///
+    /// assert_eq!(grid_b[v(2, 3)], 13);
+    ///
+    /// let grid_c = grid_b.map(ToString::to_string);
+    ///
+    /// assert_eq!(&grid_c[v(2, 3)], "13");
+    /// ```
+    pub fn map<F, U>(&self, mut f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        let mut raw = Vec::with_capacity(self.raw.len());
+        for value in self {
+            raw.push(f(value));
+        }
+        Grid { raw, dim: self.dim }
+    }
+
+    /// Maps the values and positions of an existing grid to create a new grid with the same dimensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid_a: Grid<i64> = Grid::new(5, 6, 3);
+    ///
+    /// let grid_b = grid_a.pos_map(|pos, value| *value + pos.x);
+    ///
+    /// assert_eq!(grid_b[v(1, 4)], 4);
+    /// assert_eq!(grid_b[v(3, 0)], 6);
+    ///
+    /// let grid_c = grid_b.pos_map(|pos, value| *value + pos.y);
+    ///
+    /// assert_eq!(grid_c[v(1, 4)], 8);
+    /// ```
+    pub fn pos_map<F, U>(&self, mut f: F) -> Grid<U>
+    where
+        F: FnMut(Vector, &T) -> U,
+    {
+        let mut raw = Vec::with_capacity(self.raw.len());
+        for (pos, value) in self.iter_positions() {
+            raw.push(f(pos, value));
+        }
+        Grid { raw, dim: self.dim }
+    }
+
+    /// Maps the values of an existing grid with a fallible function to create a new grid with the same dimensions.
+    ///
+    /// Returns the first error returned by `f`, in row-major order, without calling `f` on any later values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<char> = Grid::from_char_str("12\n34").unwrap();
+    ///
+    /// let digits = grid.try_map(|c| c.to_digit(10).ok_or(*c));
+    ///
+    /// assert_eq!(digits, Ok(Grid::from_nested_iter([[1, 2], [3, 4]])));
+    ///
+    /// let grid: Grid<char> = Grid::from_char_str("1x\ny4").unwrap();
+    /// let mut calls = 0;
+    ///
+    /// let digits = grid.try_map(|c| {
+    ///     calls += 1;
+    ///     c.to_digit(10).ok_or(*c)
+    /// });
+    ///
+    /// assert_eq!(digits, Err('x'));
+    /// assert_eq!(calls, 2);
+    /// ```
+    pub fn try_map<F, U, E>(&self, mut f: F) -> Result<Grid<U>, E>
+    where
+        F: FnMut(&T) -> Result<U, E>,
+    {
+        let mut raw = Vec::with_capacity(self.raw.len());
+        for value in self {
+            raw.push(f(value)?);
+        }
+        Ok(Grid { raw, dim: self.dim })
+    }
+
+    /// Maps the values and positions of an existing grid with a fallible function to create a new grid with the same
+    /// dimensions.
+    ///
+    /// Returns the first error returned by `f`, in row-major order, without calling `f` on any later values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::from_nested_iter([[3, 2], [1, 0]]);
+    ///
+    /// let sums = grid.try_pos_map(|pos, value| (*value > 0).then(|| value + pos.x).ok_or(pos));
+    ///
+    /// assert_eq!(sums, Err(v(1, 1)));
+    /// assert_eq!(grid.try_pos_map(|pos, value| Ok::<_, ()>(value + pos.x + pos.y * 2)), Ok(Grid::new(2, 2, 3)));
+    /// ```
+    pub fn try_pos_map<F, U, E>(&self, mut f: F) -> Result<Grid<U>, E>
+    where
+        F: FnMut(Vector, &T) -> Result<U, E>,
+    {
+        let mut raw = Vec::with_capacity(self.raw.len());
+        for (pos, value) in self.iter_positions() {
+            raw.push(f(pos, value)?);
+        }
+        Ok(Grid { raw, dim: self.dim })
+    }
+
+    /// Maps the values of an existing grid to create a new grid with the same dimensions.
+    ///
+    /// Consumes `self`, moving each value into `f` so that values which are not [`Clone`] can be transformed.
+    /// The allocation of `self` may be reused where `T` and `U` have the same size and alignment, but this is not guaranteed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid_a: Grid<u8> = Grid::new(15, 14, 11);
+    ///
+    /// let grid_b = grid_a.map_into(|value| value + 2);
+    ///
+    /// assert_eq!(grid_b[v(2, 3)], 13);
+    ///
+    /// let names: Grid<String> = Grid::from_nested_iter([["a".to_string(), "b".to_string()]]);
+    /// let boxed: Grid<Box<str>> = names.map_into(String::into_boxed_str);
+    ///
+    /// assert_eq!(&*boxed[v(1, 0)], "b");
+    /// ```
+    pub fn map_into<F, U>(self, f: F) -> Grid<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        Grid {
+            raw: self.raw.into_iter().map(f).collect(),
+            dim: self.dim,
+        }
+    }
+
+    /// Maps the values and positions of an existing grid to create a new grid with the same dimensions.
+    ///
+    /// Consumes `self`, moving each value into `f` so that values which are not [`Clone`] can be transformed.
+    /// The allocation of `self` may be reused where `T` and `U` have the same size and alignment, but this is not guaranteed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid_a: Grid<i64> = Grid::new(5, 6, 3);
+    ///
+    /// let grid_b = grid_a.pos_map_into(|pos, value| value + pos.x);
+    ///
+    /// assert_eq!(grid_b[v(1, 4)], 4);
+    /// assert_eq!(grid_b[v(3, 0)], 6);
+    /// ```
+    pub fn pos_map_into<F, U>(self, mut f: F) -> Grid<U>
+    where
+        F: FnMut(Vector, T) -> U,
+    {
+        let width = self.width() as usize;
+        let raw = self
+            .raw
+            .into_iter()
+            .enumerate()
+            .map(|(i, value)| f(Vector::new((i % width) as i64, (i / width) as i64), value))
+            .collect();
+        Grid { raw, dim: self.dim }
+    }
+
+    /// Applies the closure `f` to every value in the grid in place, in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<u8> = Grid::new(5, 6, 3);
+    ///
+    /// grid.map_mut(|value| *value += 1);
+    ///
+    /// assert_eq!(grid[v(2, 3)], 4);
+    /// assert_eq!(grid[v(4, 5)], 4);
+    /// ```
+    pub fn map_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        self.iter_mut().for_each(f);
+    }
+
+    /// Applies the closure `f` to every position and value in the grid in place, in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<i64> = Grid::new(5, 6, 3);
+    ///
+    /// grid.pos_map_mut(|pos, value| *value += pos.x * pos.y);
+    ///
+    /// assert_eq!(grid[v(2, 3)], 9);
+    /// assert_eq!(grid[v(0, 5)], 3);
+    ///
+    /// let mut order = Vec::new();
+    /// grid.pos_map_mut(|pos, _| order.push(pos));
+    ///
+    /// assert!(order.into_iter().eq(grid.positions()));
+    /// ```
+    pub fn pos_map_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(Vector, &mut T),
+    {
+        for (pos, value) in self.iter_mut_positions() {
+            f(pos, value);
+        }
+    }
+
+    /// Combines the values of `other` into the grid, with the top left of `other` placed at `at`.
+    ///
+    /// `blend` is called with each value of the grid that `other` covers and the corresponding value of `other`.
+    /// Any part of `other` that falls outside the grid is clipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<u8> = Grid::new(4, 3, 0);
+    /// let stamp: Grid<u8> = Grid::from_nested_iter([[1, 0, 2], [3, 4, 5]]);
+    ///
+    /// // skip transparent tiles
+    /// grid.overlay(&stamp, v(2, 2), |dst, src| {
+    ///     if *src != 0 {
+    ///         *dst = *src;
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(
+    ///     grid,
+    ///     Grid::from_nested_iter([
+    ///         [0, 0, 0, 0],
+    ///         [0, 0, 0, 0],
+    ///         [0, 0, 1, 0],
+    ///     ])
+    /// );
+    ///
+    /// grid.overlay(&stamp, v(-1, -1), |dst, src| *dst += *src);
+    ///
+    /// assert_eq!(grid[v(0, 0)], 4);
+    /// assert_eq!(grid[v(1, 0)], 5);
+    /// assert_eq!(grid[v(2, 0)], 0);
+    /// ```
+    pub fn overlay<F>(&mut self, other: &Self, at: Vector, mut blend: F)
     where
-        F: FnMut(Vector, &T) -> U,
+        F: FnMut(&mut T, &T),
     {
-        let mut raw = Vec::with_capacity(self.raw.len());
-        for (pos, value) in self.iter_positions() {
-            raw.push(f(pos, value));
+        let Some((start, end)) = self.clip_rect(at, other.dim) else {
+            return;
+        };
+        let width = self.width() as usize;
+        let other_width = other.width() as usize;
+        let (src_x0, src_x1) = ((start.x - at.x) as usize, (end.x - at.x) as usize);
+        for y in start.y..end.y {
+            let row = y as usize * width;
+            let src_row = (y - at.y) as usize * other_width;
+            let dst = &mut self.raw[row + start.x as usize..row + end.x as usize];
+            let src = &other.raw[src_row + src_x0..src_row + src_x1];
+            for (dst, src) in dst.iter_mut().zip(src) {
+                blend(dst, src);
+            }
         }
-        Grid { raw, dim: self.dim }
     }
 
-    /// Maps the values of an existing grid to create a new grid with the same dimensions.
+    /// Combines the values of two grids with the same dimensions to create a new grid with those dimensions.
     ///
-    /// Consumes `self`.
+    /// Returns `None` if the dimensions of `other` are not the same as those of `self`.
+    /// This is equivalent to [`zip_with`](Self::zip_with).
     ///
     /// # Examples
     ///
     /// ```
     /// use grid::prelude::*;
     ///
-    /// let grid_a: Grid<u8> = Grid::new(15, 14, 11);
+    /// let grid_a: Grid<i64> = Grid::from_fn(3, 2, |pos| pos.x);
+    /// let grid_b: Grid<bool> = Grid::from_fn(3, 2, |pos| pos.y == 1);
     ///
-    /// let grid_b = grid_a.map_into(|value| value + 2);
+    /// let grid_c = grid_a.zip(&grid_b, |a, b| if *b { -a } else { *a });
     ///
-    /// assert_eq!(grid_b[v(2, 3)], 13);
+    /// assert_eq!(grid_c, Some(Grid::from_nested_iter([[0, 1, 2], [0, -1, -2]])));
+    ///
+    /// assert_eq!(grid_a.zip(&Grid::new(2, 3, true), |a, _| *a), None);
     /// ```
-    pub fn map_into<F, U>(self, mut f: F) -> Grid<U>
+    pub fn zip<U, F, V>(&self, other: &Grid<U>, f: F) -> Option<Grid<V>>
     where
-        F: FnMut(T) -> U,
+        F: FnMut(&T, &U) -> V,
     {
-        let mut raw = Vec::with_capacity(self.raw.len());
-        let dim = self.dim;
-        for value in self {
-            raw.push(f(value));
-        }
-        Grid { raw, dim }
+        self.zip_with(other, f)
     }
 
-    /// Maps the values and positions of an existing grid to create a new grid with the same dimensions.
+    /// Combines the values of two grids with the same dimensions to create a new grid with those dimensions.
     ///
-    /// Consumes `self`.
+    /// `f` is called once for each pair of values at the same position, in row-major order.
+    /// Returns `None` without calling `f` if the dimensions of `other` are not the same as those of `self`.
     ///
     /// # Examples
     ///
     /// ```
     /// use grid::prelude::*;
     ///
-    /// let grid_a: Grid<i64> = Grid::new(5, 6, 3);
+    /// let grid_a: Grid<i64> = Grid::from_nested_iter([[1, 2], [3, 4]]);
+    /// let grid_b: Grid<i64> = Grid::from_nested_iter([[10, 20], [30, 40]]);
     ///
-    /// let grid_b = grid_a.pos_map_into(|pos, value| value + pos.x);
+    /// let mut order = Vec::new();
+    /// let sum = grid_a.zip_with(&grid_b, |a, b| {
+    ///     order.push(*a);
+    ///     a + b
+    /// });
     ///
-    /// assert_eq!(grid_b[v(1, 4)], 4);
-    /// assert_eq!(grid_b[v(3, 0)], 6);
+    /// assert_eq!(sum, Some(Grid::from_nested_iter([[11, 22], [33, 44]])));
+    /// assert_eq!(order, vec![1, 2, 3, 4]);
     /// ```
-    pub fn pos_map_into<F, U>(self, mut f: F) -> Grid<U>
+    pub fn zip_with<U, V, F>(&self, other: &Grid<U>, mut f: F) -> Option<Grid<V>>
     where
-        F: FnMut(Vector, T) -> U,
+        F: FnMut(&T, &U) -> V,
     {
+        if !self.same_dim(other) {
+            return None;
+        }
         let mut raw = Vec::with_capacity(self.raw.len());
-        let dim = self.dim;
-        for (pos, value) in self.into_iter_positions() {
-            raw.push(f(pos, value));
+        for (a, b) in self.raw.iter().zip(&other.raw) {
+            raw.push(f(a, b));
         }
-        Grid { raw, dim }
+        Some(Grid { raw, dim: self.dim })
+    }
+
+    /// Returns `true` if `other` has the same dimensions as `self` and `close` returns `true` for the values at each
+    /// position of the two grids.
+    ///
+    /// The dimensions are compared first, so `close` is never called if they differ.
+    /// Stops at the first pair of values for which `close` returns `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid_a: Grid<f64> = Grid::from_nested_iter([[0.1 + 0.2, 1.0], [2.0, 3.0]]);
+    /// let grid_b: Grid<f64> = Grid::from_nested_iter([[0.3, 1.0], [2.0, 3.0]]);
+    ///
+    /// let close = |a: &f64, b: &f64| (a - b).abs() < 1e-9;
+    ///
+    /// assert_ne!(grid_a, grid_b);
+    /// assert!(grid_a.approx_eq(&grid_b, close));
+    /// assert!(!grid_a.approx_eq(&Grid::new(4, 1, 1.0), close));
+    ///
+    /// let mut calls = 0;
+    /// grid_a.approx_eq(&Grid::new(2, 2, 9.0), |_, _| {
+    ///     calls += 1;
+    ///     false
+    /// });
+    ///
+    /// assert_eq!(calls, 1);
+    /// ```
+    pub fn approx_eq<F>(&self, other: &Self, mut close: F) -> bool
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        self.same_dim(other) && self.raw.iter().zip(&other.raw).all(|(a, b)| close(a, b))
+    }
+
+    /// Returns the number of values in the grid for which `pred` returns `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<char> = Grid::from_char_str("#.#\n..#").unwrap();
+    ///
+    /// assert_eq!(grid.count(|c| *c == '#'), 3);
+    /// ```
+    pub fn count<F>(&self, pred: F) -> usize
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.iter().filter(|value| pred(value)).count()
+    }
+
+    /// Returns the number of values in the grid equal to `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<char> = Grid::from_char_str("#.#\n..#").unwrap();
+    ///
+    /// assert_eq!(grid.count_value(&'.'), 3);
+    /// assert_eq!(grid.count_value(&'x'), 0);
+    /// ```
+    pub fn count_value(&self, value: &T) -> usize
+    where
+        T: PartialEq,
+    {
+        self.count(|other| other == value)
+    }
+
+    /// Returns the number of positions and values in the grid for which `pred` returns `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<char> = Grid::from_char_str("#.#\n..#\n###").unwrap();
+    ///
+    /// let on_border = |pos: Vector| pos.x == 0 || pos.y == 0 || pos.x == 2 || pos.y == 2;
+    ///
+    /// assert_eq!(grid.count_positions(|pos, c| on_border(pos) && *c == '#'), 6);
+    /// ```
+    pub fn count_positions<F>(&self, pred: F) -> usize
+    where
+        F: Fn(Vector, &T) -> bool,
+    {
+        self.iter_positions()
+            .filter(|&(pos, value)| pred(pos, value))
+            .count()
+    }
+
+    /// Returns the number of in-bounds values adjacent to the given position for which `pred` returns `true`.
+    ///
+    /// Neighbours outside the grid are not counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<char> = Grid::from_char_str("##.\n#.#\n.##").unwrap();
+    ///
+    /// assert_eq!(grid.count_adjacent(v(1, 1), |c| *c == '#'), 6);
+    /// assert_eq!(grid.count_adjacent(v(0, 0), |c| *c == '#'), 2);
+    /// assert_eq!(grid.count_adjacent(v(0, 0), |_| true), 3);
+    /// assert_eq!(grid.count_orthogonal(v(1, 1), |c| *c == '#'), 4);
+    /// assert_eq!(grid.count_orthogonal(v(2, 2), |c| *c == '#'), 2);
+    /// ```
+    pub fn count_adjacent<F>(&self, pos: Vector, pred: F) -> usize
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.count_neighbors_with(pos, pred, &ADJACENT)
+    }
+
+    /// Returns the number of in-bounds values orthogonally adjacent to the given position for which `pred` returns
+    /// `true`.
+    ///
+    /// Neighbours outside the grid are not counted.
+    pub fn count_orthogonal<F>(&self, pos: Vector, pred: F) -> usize
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.count_neighbors_with(pos, pred, &ORTHOGONAL)
+    }
+
+    fn count_neighbors_with<F>(&self, pos: Vector, pred: F, offsets: &[Vector]) -> usize
+    where
+        F: Fn(&T) -> bool,
+    {
+        offsets
+            .iter()
+            .filter_map(|&offset| self.get(pos + offset))
+            .filter(|&value| pred(value))
+            .count()
+    }
+
+    /// Returns the position and value of the greatest value in the grid according to `cmp`, or `None` if the grid is empty.
+    ///
+    /// If several values are equally greatest, the first in row-major order is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<(u8, char)> = Grid::from_nested_iter([[(1, 'a'), (3, 'b')], [(3, 'c'), (2, 'd')]]);
+    ///
+    /// assert_eq!(grid.argmax_by(|a, b| a.0.cmp(&b.0)), Some((v(1, 0), &(3, 'b'))));
+    /// assert_eq!(grid.argmax_by(|a, b| a.1.cmp(&b.1)), Some((v(1, 1), &(2, 'd'))));
+    /// ```
+    pub fn argmax_by<F>(&self, mut cmp: F) -> Option<(Vector, &T)>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.iter_positions()
+            .reduce(|best, next| match cmp(next.1, best.1) {
+                Ordering::Greater => next,
+                _ => best,
+            })
+    }
+
+    /// Returns the position and value of the least value in the grid according to `cmp`, or `None` if the grid is empty.
+    ///
+    /// If several values are equally least, the first in row-major order is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::from_nested_iter([[4, -2, 5], [-2, 0, 7]]);
+    ///
+    /// assert_eq!(grid.argmin_by(|a, b| a.abs().cmp(&b.abs())), Some((v(1, 1), &0)));
+    /// ```
+    pub fn argmin_by<F>(&self, mut cmp: F) -> Option<(Vector, &T)>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.iter_positions()
+            .reduce(|best, next| match cmp(next.1, best.1) {
+                Ordering::Less => next,
+                _ => best,
+            })
+    }
+
+    /// Returns the position and value of the greatest value in the grid, or `None` if the grid is empty.
+    ///
+    /// If several values are equally greatest, the first in row-major order is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let heat: Grid<u8> = Grid::from_nested_iter([[1, 9, 3], [9, 2, 9]]);
+    ///
+    /// assert_eq!(heat.argmax(), Some((v(1, 0), &9)));
+    /// ```
+    pub fn argmax(&self) -> Option<(Vector, &T)>
+    where
+        T: Ord,
+    {
+        self.argmax_by(T::cmp)
+    }
+
+    /// Returns the position and value of the least value in the grid, or `None` if the grid is empty.
+    ///
+    /// If several values are equally least, the first in row-major order is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let heat: Grid<u8> = Grid::from_nested_iter([[4, 1, 3], [1, 2, 1]]);
+    ///
+    /// assert_eq!(heat.argmin(), Some((v(1, 0), &1)));
+    /// ```
+    pub fn argmin(&self) -> Option<(Vector, &T)>
+    where
+        T: Ord,
+    {
+        self.argmin_by(T::cmp)
+    }
+
+    /// Folds the values of each row of the grid into a single value, returning one value per row from top to bottom.
+    ///
+    /// Each row starts from a value returned by `init`, and `f` is applied to the values of the row from left to right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::from_nested_iter([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// assert_eq!(grid.fold_rows(|| 0, |sum, value| sum + value), vec![6, 15]);
+    /// assert_eq!(
+    ///     grid.fold_rows(String::new, |s, value| s + &value.to_string()),
+    ///     vec!["123", "456"]
+    /// );
+    /// ```
+    pub fn fold_rows<A, I, F>(&self, mut init: I, mut f: F) -> Vec<A>
+    where
+        I: FnMut() -> A,
+        F: FnMut(A, &T) -> A,
+    {
+        self.rows()
+            .map(|row| row.iter().fold(init(), &mut f))
+            .collect()
+    }
+
+    /// Folds the values of each column of the grid into a single value, returning one value per column from left to right.
+    ///
+    /// Each column starts from a value returned by `init`, and `f` is applied to the values of the column from top to bottom.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::from_nested_iter([[1, 7, 3], [4, 5, 6]]);
+    ///
+    /// assert_eq!(grid.fold_cols(|| 0, |sum, value| sum + value), vec![5, 12, 9]);
+    /// assert_eq!(grid.fold_cols(|| i64::MIN, |max, &value| max.max(value)), vec![4, 7, 6]);
+    /// assert_eq!(
+    ///     grid.fold_cols(String::new, |s, value| s + &value.to_string()),
+    ///     vec!["14", "75", "36"]
+    /// );
+    /// ```
+    pub fn fold_cols<A, I, F>(&self, mut init: I, mut f: F) -> Vec<A>
+    where
+        I: FnMut() -> A,
+        F: FnMut(A, &T) -> A,
+    {
+        self.cols().map(|col| col.fold(init(), &mut f)).collect()
     }
 }
 
+/// Indexes the grid by position.
+///
+/// Panics if the position is out of bounds, with a message giving both the position and the dimensions of the grid.
+/// For a non-panicking alternative see [`Grid::get`].
+///
+/// # Examples
+///
+/// ```
+/// use grid::prelude::*;
+///
+/// let grid: Grid<u8> = Grid::new(8, 10, 0);
+///
+/// assert_eq!(grid[v(7, 9)], 0);
+///
+/// let panic = std::panic::catch_unwind(|| grid[v(9, 3)]).unwrap_err();
+///
+/// assert_eq!(
+///     panic.downcast_ref::<String>().unwrap(),
+///     "position out of bounds: the dimensions are (8, 10) but the position is (9, 3)"
+/// );
+/// ```
 impl<T> Index<Vector> for Grid<T> {
     type Output = T;
 
     #[track_caller]
     fn index(&self, pos: Vector) -> &Self::Output {
         let dim = self.dim;
-        if let Some(r) = self.get(pos) {
-            return r;
+        match self.get(pos) {
+            Some(value) => value,
+            None => out_of_bounds(pos, dim),
         }
-        panic!("position out of bounds: the dimensions are {dim} but the position is {pos}")
     }
 }
 
@@ -516,10 +3219,10 @@ impl<T> IndexMut<Vector> for Grid<T> {
     #[track_caller]
     fn index_mut(&mut self, pos: Vector) -> &mut Self::Output {
         let dim = self.dim;
-        if let Some(r) = self.get_mut(pos) {
-            return r;
+        match self.get_mut(pos) {
+            Some(value) => value,
+            None => out_of_bounds(pos, dim),
         }
-        panic!("position out of bounds: the dimensions are {dim} but the position is {pos}")
     }
 }
 
@@ -547,13 +3250,136 @@ impl<T: fmt::Display> fmt::Debug for Grid<T> {
     }
 }
 
-#[track_caller]
-fn size(width: i64, height: i64) -> usize {
+/// Formats the grid with each row on its own line and no separators between values.
+///
+/// # Examples
+///
+/// ```
+/// use grid::prelude::*;
+///
+/// let grid: Grid<char> = Grid::from_nested_iter([
+///     ['#', '#', '#'],
+///     ['#', '.', '.'],
+///     ['#', '#', '#'],
+/// ]);
+///
+/// assert_eq!(grid.to_string(), "###\n#..\n###");
+/// ```
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y != 0 {
+                writeln!(f)?;
+            }
+            for value in row {
+                write!(f, "{value}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses a grid of characters with one row per line, as by [`Grid::from_char_str`].
+///
+/// Both `\n` and `\r\n` line endings are accepted and a single trailing line ending is ignored.
+///
+/// # Examples
+///
+/// ```
+/// use grid::prelude::*;
+///
+/// let grid: Grid<char> = "#.\r\n.#\n".parse().unwrap();
+///
+/// assert_eq!(grid, Grid::from_nested_iter([['#', '.'], ['.', '#']]));
+/// assert!("ab\nc".parse::<Grid<char>>().is_err());
+/// ```
+impl FromStr for Grid<char> {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, GridError> {
+        Self::from_char_str(s)
+    }
+}
+
+/// Parses a grid of bytes with one row per line, where each byte of a line is one value.
+///
+/// Lines are split as by [`str::lines`], so both `\n` and `\r\n` line endings are accepted and a single trailing
+/// line ending is ignored. Returns errors in the same cases as [`Grid::from_str_with`].
+///
+/// # Examples
+///
+/// ```
+/// use grid::prelude::*;
+///
+/// let grid: Grid<u8> = "09\r\n18\n".parse().unwrap();
+///
+/// assert_eq!(grid, Grid::from_nested_iter([[b'0', b'9'], [b'1', b'8']]));
+/// assert_eq!("".parse::<Grid<u8>>(), Err(GridError::EmptyInput));
+/// assert_eq!(
+///     "ab\nc".parse::<Grid<u8>>(),
+///     Err(GridError::RaggedRows {
+///         expected: 2,
+///         found: 1,
+///         row: 1
+///     })
+/// );
+/// ```
+impl FromStr for Grid<u8> {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, GridError> {
+        if s.lines().all(str::is_empty) {
+            return Err(GridError::EmptyInput);
+        }
+        Grid::from_rows(s.lines().map(str::bytes))
+    }
+}
+
+fn push_row<T, R>(raw: &mut Vec<T>, row: R) -> i64
+where
+    R: IntoIterator<Item = T>,
+{
+    let len = raw.len();
+    raw.extend(row);
+    (raw.len() - len) as i64
+}
+
+/// Returns the number of values in a grid with the given dimensions,
+/// or `None` if the dimensions are not positive or too large for a grid to be constructed.
+///
+/// # Examples
+///
+/// ```
+/// use grid::checked_size;
+///
+/// assert_eq!(checked_size(8, 10), Some(80));
+/// assert_eq!(checked_size(0, 10), None);
+/// assert_eq!(checked_size(-3, 4), None);
+/// assert_eq!(checked_size(i64::MAX, i64::MAX), None);
+/// ```
+pub fn checked_size(width: i64, height: i64) -> Option<usize> {
+    size(width, height).ok()
+}
+
+fn size(width: i64, height: i64) -> Result<usize, GridError> {
     if width <= 0 || height <= 0 {
-        panic!("dimensions must be positive: ({width}, {height})");
+        return Err(GridError::NonPositiveDimensions { width, height });
     }
-    if let Some(size) = (width as usize).checked_mul(height as usize) {
-        return size;
+    (width as usize)
+        .checked_mul(height as usize)
+        .ok_or(GridError::CapacityOverflow { width, height })
+}
+
+#[cold]
+#[track_caller]
+fn out_of_bounds(pos: Vector, dim: Vector) -> ! {
+    panic!("{}", GridError::OutOfBounds { pos, dim })
+}
+
+#[track_caller]
+fn unwrap<T>(result: Result<T, GridError>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(err) => panic!("{err}"),
     }
-    panic!("dimensions are too large: ({width}, {height})");
 }
@@ -0,0 +1,578 @@
+//! Graph algorithms that treat a `Grid` as a network of connected cells.
+
+use crate::{
+    grid::Grid,
+    vector::{
+        constants::{ADJACENT, ORTHOGONAL},
+        Vector,
+    },
+};
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, VecDeque},
+};
+
+impl<T: Clone + PartialEq> Grid<T> {
+    /// Replaces every value in the orthogonally connected region of values equal to the value at `start` with `new_value`.
+    ///
+    /// Does nothing if `start` is out of bounds or the value at `start` is already equal to `new_value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<u8> = Grid::from_nested_iter([
+    ///     [0, 0, 1, 0],
+    ///     [0, 1, 0, 0],
+    ///     [1, 0, 0, 1],
+    /// ]);
+    ///
+    /// grid.flood_fill(v(3, 0), 2);
+    ///
+    /// assert_eq!(
+    ///     grid,
+    ///     Grid::from_nested_iter([
+    ///         [0, 0, 1, 2],
+    ///         [0, 1, 2, 2],
+    ///         [1, 2, 2, 1],
+    ///     ])
+    /// );
+    /// ```
+    pub fn flood_fill(&mut self, start: Vector, new_value: T) {
+        self.flood_fill_with(start, new_value, &ORTHOGONAL);
+    }
+
+    /// Replaces every value in the region of values equal to the value at `start` with `new_value`,
+    /// where two positions are connected if they differ by one of the given offsets.
+    ///
+    /// Does nothing if `start` is out of bounds or the value at `start` is already equal to `new_value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<u8> = Grid::from_nested_iter([
+    ///     [0, 0, 1, 0],
+    ///     [0, 1, 0, 0],
+    ///     [1, 0, 0, 1],
+    /// ]);
+    ///
+    /// grid.flood_fill_with(v(2, 0), 2, &ADJACENT);
+    ///
+    /// assert_eq!(
+    ///     grid,
+    ///     Grid::from_nested_iter([
+    ///         [0, 0, 2, 0],
+    ///         [0, 2, 0, 0],
+    ///         [2, 0, 0, 1],
+    ///     ])
+    /// );
+    /// ```
+    pub fn flood_fill_with(&mut self, start: Vector, new_value: T, offsets: &[Vector]) {
+        let Some(target) = self.get(start).cloned() else {
+            return;
+        };
+        if target == new_value {
+            return;
+        }
+        let mut stack = vec![start];
+        while let Some(pos) = stack.pop() {
+            let Some(value) = self.get_mut(pos) else {
+                continue;
+            };
+            if *value != target {
+                continue;
+            }
+            *value = new_value.clone();
+            for &offset in offsets {
+                stack.push(pos + offset);
+            }
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Computes the number of orthogonal steps needed to reach every position in the grid from `start`,
+    /// only moving through positions for which `passable` returns `true`.
+    ///
+    /// Returns a grid of the same dimensions containing `Some(steps)` for every reachable position and `None` for every other position.
+    /// If `start` is out of bounds or not passable, every position is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<char> = Grid::from_nested_iter([
+    ///     ['.', '.', '#', '.'],
+    ///     ['.', '#', '#', '.'],
+    ///     ['.', '.', '.', '.'],
+    ///     ['#', '#', '#', '#'],
+    ///     ['.', '.', '.', '.'],
+    /// ]);
+    ///
+    /// let distances = grid.bfs_distances(v(0, 0), |_, c| *c == '.');
+    ///
+    /// assert_eq!(distances[v(0, 0)], Some(0));
+    /// assert_eq!(distances[v(1, 0)], Some(1));
+    /// assert_eq!(distances[v(3, 0)], Some(7));
+    /// assert_eq!(distances[v(2, 0)], None);
+    /// assert_eq!(distances[v(1, 4)], None);
+    ///
+    /// assert!(grid.bfs_distances(v(2, 0), |_, c| *c == '.').iter().all(Option::is_none));
+    /// ```
+    pub fn bfs_distances<F>(&self, start: Vector, passable: F) -> Grid<Option<u64>>
+    where
+        F: Fn(Vector, &T) -> bool,
+    {
+        self.bfs_distances_with(start, passable, &ORTHOGONAL)
+    }
+
+    /// Computes the number of orthogonal or diagonal steps needed to reach every position in the grid from `start`,
+    /// only moving through positions for which `passable` returns `true`.
+    ///
+    /// Returns a grid of the same dimensions containing `Some(steps)` for every reachable position and `None` for every other position.
+    /// If `start` is out of bounds or not passable, every position is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<char> = Grid::from_nested_iter([
+    ///     ['.', '#', '.'],
+    ///     ['#', '.', '#'],
+    ///     ['.', '#', '.'],
+    /// ]);
+    ///
+    /// let distances = grid.bfs_distances_diagonal(v(0, 0), |_, c| *c == '.');
+    ///
+    /// assert_eq!(distances[v(1, 1)], Some(1));
+    /// assert_eq!(distances[v(2, 2)], Some(2));
+    /// assert_eq!(distances[v(1, 0)], None);
+    /// ```
+    pub fn bfs_distances_diagonal<F>(&self, start: Vector, passable: F) -> Grid<Option<u64>>
+    where
+        F: Fn(Vector, &T) -> bool,
+    {
+        self.bfs_distances_with(start, passable, &ADJACENT)
+    }
+
+    /// Computes the number of steps needed to reach every position in the grid from `start`,
+    /// where each step moves by one of the given offsets and only moves through positions for which `passable` returns `true`.
+    ///
+    /// Returns a grid of the same dimensions containing `Some(steps)` for every reachable position and `None` for every other position.
+    /// If `start` is out of bounds or not passable, every position is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::new(5, 5, 0);
+    ///
+    /// let distances = grid.bfs_distances_with(v(0, 0), |_, _| true, &[v(1, 2), v(2, 1)]);
+    ///
+    /// assert_eq!(distances[v(3, 3)], Some(2));
+    /// assert_eq!(distances[v(1, 0)], None);
+    /// ```
+    pub fn bfs_distances_with<F>(
+        &self,
+        start: Vector,
+        passable: F,
+        offsets: &[Vector],
+    ) -> Grid<Option<u64>>
+    where
+        F: Fn(Vector, &T) -> bool,
+    {
+        let mut distances = self.map(|_| None);
+        if !self.get(start).is_some_and(|value| passable(start, value)) {
+            return distances;
+        }
+        distances[start] = Some(0);
+        let mut queue = VecDeque::from([(start, 0)]);
+        while let Some((pos, steps)) = queue.pop_front() {
+            for &offset in offsets {
+                let next = pos + offset;
+                let Some(value) = self.get(next) else {
+                    continue;
+                };
+                if distances[next].is_none() && passable(next, value) {
+                    distances[next] = Some(steps + 1);
+                    queue.push_back((next, steps + 1));
+                }
+            }
+        }
+        distances
+    }
+
+    /// Computes the manhattan distance from every position in the grid to the nearest position for which `is_feature` returns `true`.
+    ///
+    /// Returns a grid of the same dimensions containing `Some(distance)` for every position, or `None` for every position
+    /// if there are no features, consistent with [`Grid::bfs_distances`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<char> = Grid::from_char_str("#....\n.....\n....#").unwrap();
+    ///
+    /// let distances = grid.distance_transform(|c| *c == '#');
+    ///
+    /// assert_eq!(
+    ///     distances.map(|d| d.unwrap()),
+    ///     Grid::from_nested_iter([[0, 1, 2, 3, 2], [1, 2, 3, 2, 1], [2, 3, 2, 1, 0]])
+    /// );
+    ///
+    /// assert!(grid.distance_transform(|c| *c == '@').iter().all(Option::is_none));
+    /// ```
+    pub fn distance_transform<F>(&self, is_feature: F) -> Grid<Option<u64>>
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.distance_transform_with(is_feature, &ORTHOGONAL)
+    }
+
+    /// Computes the number of steps from every position in the grid to the nearest position for which `is_feature` returns `true`,
+    /// where each step moves by one of the given offsets.
+    ///
+    /// Returns a grid of the same dimensions containing `Some(steps)` for every position from which a feature can be reached,
+    /// and `None` for every other position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<char> = Grid::from_char_str("#....\n.....\n....#").unwrap();
+    ///
+    /// let distances = grid.distance_transform_with(|c| *c == '#', &ADJACENT);
+    ///
+    /// assert_eq!(distances[v(2, 1)], Some(2));
+    /// assert_eq!(distances[v(3, 0)], Some(2));
+    /// assert_eq!(distances[v(3, 1)], Some(1));
+    /// ```
+    pub fn distance_transform_with<F>(&self, is_feature: F, offsets: &[Vector]) -> Grid<Option<u64>>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut distances = self.map(|value| is_feature(value).then_some(0));
+        let mut queue: VecDeque<(Vector, u64)> = distances
+            .iter_positions()
+            .filter(|(_, distance)| distance.is_some())
+            .map(|(pos, _)| (pos, 0))
+            .collect();
+        while let Some((pos, steps)) = queue.pop_front() {
+            for &offset in offsets {
+                let next = pos + offset;
+                if let Some(distance @ None) = distances.get_mut(next) {
+                    *distance = Some(steps + 1);
+                    queue.push_back((next, steps + 1));
+                }
+            }
+        }
+        distances
+    }
+
+    /// Finds every position in the orthogonally connected region containing `start`, without modifying the grid.
+    ///
+    /// Two orthogonally adjacent positions are connected if `connected(a, b)` returns `true`, where `a` is the value at
+    /// the position already in the region and `b` is the value at its neighbour.
+    /// Returns the positions in breadth-first order starting with `start`, or an empty `Vec` if `start` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::from_nested_iter([
+    ///     [0, 0, 1, 0],
+    ///     [0, 1, 0, 0],
+    ///     [1, 0, 0, 1],
+    /// ]);
+    ///
+    /// let region = grid.connected_region(v(3, 0), |a, b| a == b);
+    ///
+    /// assert_eq!(region, vec![v(3, 0), v(3, 1), v(2, 1), v(2, 2), v(1, 2)]);
+    ///
+    /// let heights: Grid<u8> = Grid::from_nested_iter([[1, 2, 3], [9, 9, 4], [7, 6, 5]]);
+    /// let uphill = heights.connected_region(v(0, 0), |a, b| b == &(a + 1));
+    ///
+    /// assert_eq!(uphill.len(), 7);
+    /// assert_eq!(uphill.last(), Some(&v(0, 2)));
+    ///
+    /// assert!(grid.connected_region(v(4, 0), |a, b| a == b).is_empty());
+    /// ```
+    pub fn connected_region<F>(&self, start: Vector, connected: F) -> Vec<Vector>
+    where
+        F: Fn(&T, &T) -> bool,
+    {
+        self.connected_region_with(start, connected, &ORTHOGONAL)
+    }
+
+    /// Finds every position in the connected region containing `start`, without modifying the grid,
+    /// where two positions are adjacent if they differ by one of the given offsets.
+    ///
+    /// Adjacent positions are connected as in [`Grid::connected_region`], and the positions are returned in breadth-first order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::from_nested_iter([
+    ///     [0, 0, 1, 0],
+    ///     [0, 1, 0, 0],
+    ///     [1, 0, 0, 1],
+    /// ]);
+    ///
+    /// let region = grid.connected_region_with(v(2, 0), |a, b| a == b, &ADJACENT);
+    ///
+    /// assert_eq!(region, vec![v(2, 0), v(1, 1), v(0, 2)]);
+    /// ```
+    pub fn connected_region_with<F>(
+        &self,
+        start: Vector,
+        connected: F,
+        offsets: &[Vector],
+    ) -> Vec<Vector>
+    where
+        F: Fn(&T, &T) -> bool,
+    {
+        if !self.in_bounds(start) {
+            return Vec::new();
+        }
+        let mut visited = self.map(|_| false);
+        visited[start] = true;
+        let mut region = vec![start];
+        let mut i = 0;
+        while let Some(&pos) = region.get(i) {
+            i += 1;
+            for &offset in offsets {
+                let next = pos + offset;
+                let Some(value) = self.get(next) else {
+                    continue;
+                };
+                if !visited[next] && connected(&self[pos], value) {
+                    visited[next] = true;
+                    region.push(next);
+                }
+            }
+        }
+        region
+    }
+
+    /// Finds a cheapest orthogonal path from `start` to `goal` using the A* search algorithm.
+    ///
+    /// `cost(from, to, value)` gives the cost of moving from `from` into the neighbouring position `to` with value `value`,
+    /// or `None` if `to` cannot be entered. `heuristic(pos)` estimates the remaining cost from `pos` to `goal`,
+    /// and must never overestimate it for the returned path to be the cheapest.
+    ///
+    /// Returns the positions on the path including both `start` and `goal`, or `None` if either is out of bounds or `goal` is unreachable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<char> = Grid::from_char_str(
+    ///     "..#...\n\
+    ///      #.#.#.\n\
+    ///      #...#.",
+    /// )
+    /// .unwrap();
+    ///
+    /// let goal = v(5, 0);
+    /// let path = grid
+    ///     .astar(
+    ///         v(0, 0),
+    ///         goal,
+    ///         |_, _, c| (*c == '.').then_some(1),
+    ///         |pos| pos.manhattan(goal) as u64,
+    ///     )
+    ///     .unwrap();
+    ///
+    /// assert_eq!(path.len(), 10);
+    /// assert_eq!(path.first(), Some(&v(0, 0)));
+    /// assert_eq!(path.last(), Some(&goal));
+    /// assert!(path.windows(2).all(|step| step[0].manhattan(step[1]) == 1));
+    /// assert!(path.iter().all(|&pos| grid[pos] == '.'));
+    ///
+    /// assert_eq!(grid.astar(v(0, 0), v(0, 2), |_, _, c| (*c == '.').then_some(1), |_| 0), None);
+    /// ```
+    pub fn astar<F, H>(
+        &self,
+        start: Vector,
+        goal: Vector,
+        cost: F,
+        heuristic: H,
+    ) -> Option<Vec<Vector>>
+    where
+        F: Fn(Vector, Vector, &T) -> Option<u64>,
+        H: Fn(Vector) -> u64,
+    {
+        if !self.in_bounds(start) || !self.in_bounds(goal) {
+            return None;
+        }
+        let mut best = self.map(|_| None);
+        let mut previous = self.map(|_| None);
+        let mut heap = BinaryHeap::from([Reverse((heuristic(start), 0, start))]);
+        best[start] = Some(0);
+        while let Some(Reverse((_, steps, pos))) = heap.pop() {
+            if pos == goal {
+                let mut path = vec![pos];
+                while let Some(pos) = previous[*path.last()?] {
+                    path.push(pos);
+                }
+                path.reverse();
+                return Some(path);
+            }
+            if best[pos].is_some_and(|best| steps > best) {
+                continue;
+            }
+            for offset in ORTHOGONAL {
+                let next = pos + offset;
+                let Some(value) = self.get(next) else {
+                    continue;
+                };
+                let Some(step) = cost(pos, next, value) else {
+                    continue;
+                };
+                let steps = steps + step;
+                if best[next].is_none_or(|best| steps < best) {
+                    best[next] = Some(steps);
+                    previous[next] = Some(pos);
+                    heap.push(Reverse((steps + heuristic(next), steps, next)));
+                }
+            }
+        }
+        None
+    }
+
+    /// Finds a cheapest orthogonal path from `start` to `goal` using the A* search algorithm,
+    /// with the manhattan distance to `goal` as the heuristic.
+    ///
+    /// The manhattan distance never overestimates the remaining cost as long as every move costs at least `1`.
+    ///
+    /// See [`Grid::astar`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u64> = Grid::from_nested_iter([
+    ///     [1, 9, 1],
+    ///     [1, 9, 1],
+    ///     [1, 1, 1],
+    /// ]);
+    ///
+    /// let path = grid.astar_manhattan(v(0, 0), v(2, 0), |_, _, cost| Some(*cost)).unwrap();
+    ///
+    /// assert_eq!(path, [v(0, 0), v(0, 1), v(0, 2), v(1, 2), v(2, 2), v(2, 1), v(2, 0)]);
+    /// ```
+    pub fn astar_manhattan<F>(&self, start: Vector, goal: Vector, cost: F) -> Option<Vec<Vector>>
+    where
+        F: Fn(Vector, Vector, &T) -> Option<u64>,
+    {
+        self.astar(start, goal, cost, |pos| pos.manhattan(goal) as u64)
+    }
+
+    /// Labels the orthogonally connected regions of positions for which `is_foreground` returns `true`.
+    ///
+    /// Returns a grid of the same dimensions containing `0` for background positions and a label from `1` to `n` for foreground positions,
+    /// along with the number of regions `n`. Regions are labelled in the order in which they are first reached in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<char> = Grid::from_char_str("##..\n#.#.\n..##").unwrap();
+    ///
+    /// let (labels, count) = grid.label_components(|c| *c == '#');
+    ///
+    /// assert_eq!(count, 2);
+    /// assert_eq!(labels, Grid::from_nested_iter([[1, 1, 0, 0], [1, 0, 2, 0], [0, 0, 2, 2]]));
+    /// ```
+    pub fn label_components<F>(&self, is_foreground: F) -> (Grid<u32>, u32)
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.label_components_with(is_foreground, &ORTHOGONAL)
+    }
+
+    /// Labels the orthogonally or diagonally connected regions of positions for which `is_foreground` returns `true`.
+    ///
+    /// See [`Grid::label_components`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<char> = Grid::from_char_str("##..\n#.#.\n..##").unwrap();
+    ///
+    /// let (labels, count) = grid.label_components_diagonal(|c| *c == '#');
+    ///
+    /// assert_eq!(count, 1);
+    /// assert_eq!(labels, Grid::from_nested_iter([[1, 1, 0, 0], [1, 0, 1, 0], [0, 0, 1, 1]]));
+    /// ```
+    pub fn label_components_diagonal<F>(&self, is_foreground: F) -> (Grid<u32>, u32)
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.label_components_with(is_foreground, &ADJACENT)
+    }
+
+    /// Labels the connected regions of positions for which `is_foreground` returns `true`,
+    /// where two positions are connected if they differ by one of the given offsets.
+    ///
+    /// See [`Grid::label_components`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<char> = Grid::from_char_str("#.#\n#.#").unwrap();
+    ///
+    /// let (labels, count) = grid.label_components_with(|c| *c == '#', &[EAST, WEST]);
+    ///
+    /// assert_eq!(count, 4);
+    /// assert_eq!(labels, Grid::from_nested_iter([[1, 0, 2], [3, 0, 4]]));
+    /// ```
+    pub fn label_components_with<F>(&self, is_foreground: F, offsets: &[Vector]) -> (Grid<u32>, u32)
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut labels = self.map(|_| 0);
+        let mut count = 0;
+        let mut stack = Vec::new();
+        for (start, value) in self.iter_positions() {
+            if labels[start] != 0 || !is_foreground(value) {
+                continue;
+            }
+            count += 1;
+            labels[start] = count;
+            stack.push(start);
+            while let Some(pos) = stack.pop() {
+                for &offset in offsets {
+                    let next = pos + offset;
+                    let Some(value) = self.get(next) else {
+                        continue;
+                    };
+                    if labels[next] == 0 && is_foreground(value) {
+                        labels[next] = count;
+                        stack.push(next);
+                    }
+                }
+            }
+        }
+        (labels, count)
+    }
+}
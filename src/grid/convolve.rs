@@ -0,0 +1,237 @@
+//! Kernel operations over a `Grid`, with configurable handling of positions beyond its edges.
+
+use crate::{
+    grid::Grid,
+    vector::{constants::ADJACENT, Vector},
+};
+
+/// Determines how positions outside the bounds of a grid are sampled.
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub enum EdgeMode<T> {
+    /// Positions outside the grid take the value of the nearest position inside it.
+    Clamp,
+    /// Positions outside the grid wrap around to the opposite edge, as if the grid were a torus.
+    Wrap,
+    /// Positions outside the grid have no value.
+    Skip,
+    /// Positions outside the grid all take the given value.
+    Value(T),
+}
+
+impl<T> Grid<T> {
+    /// Returns a reference to the value at the given position of the grid, handling out of bounds positions according to `edge`.
+    ///
+    /// Only returns `None` if `edge` is [`EdgeMode::Skip`] and the position is out of bounds.
+    ///
+    /// Panics if the grid is empty and `edge` is [`EdgeMode::Clamp`] or [`EdgeMode::Wrap`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::from_fn(4, 3, |pos| pos.x + pos.y * 4);
+    ///
+    /// assert_eq!(grid.sample(v(-1, 1), &EdgeMode::Clamp), Some(&4));
+    /// assert_eq!(grid.sample(v(-1, 1), &EdgeMode::Wrap), Some(&7));
+    /// assert_eq!(grid.sample(v(-1, 1), &EdgeMode::Skip), None);
+    /// assert_eq!(grid.sample(v(-1, 1), &EdgeMode::Value(-1)), Some(&-1));
+    /// assert_eq!(grid.sample(v(2, 1), &EdgeMode::Skip), Some(&6));
+    /// ```
+    #[track_caller]
+    pub fn sample<'a>(&'a self, pos: Vector, edge: &'a EdgeMode<T>) -> Option<&'a T> {
+        match edge {
+            EdgeMode::Clamp => Some(self.get_clamped(pos)),
+            EdgeMode::Wrap => Some(self.get_wrapping(pos)),
+            EdgeMode::Skip => self.get(pos),
+            EdgeMode::Value(value) => Some(self.get(pos).unwrap_or(value)),
+        }
+    }
+
+    /// Creates a new grid with the same dimensions by applying the kernel `f` centred on every position, in row-major order.
+    ///
+    /// The kernel is given the centre position and a function that samples the grid at an offset relative to that centre,
+    /// handling positions beyond the edges of the grid according to `edge` as in [`Grid::sample`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::from_nested_iter([
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// ]);
+    ///
+    /// let maxima = grid.convolve(EdgeMode::Skip, |_, sample| {
+    ///     ORTHOGONAL_ZERO.iter().filter_map(|&offset| sample(offset)).max().copied().unwrap()
+    /// });
+    ///
+    /// assert_eq!(maxima, Grid::from_nested_iter([[4, 5, 6], [5, 6, 6]]));
+    /// ```
+    pub fn convolve<F, U>(&self, edge: EdgeMode<T>, mut f: F) -> Grid<U>
+    where
+        F: for<'a> FnMut(Vector, &'a dyn Fn(Vector) -> Option<&'a T>) -> U,
+    {
+        let edge = &edge;
+        self.pos_map(|pos, _| f(pos, &|offset| self.sample(pos + offset, edge)))
+    }
+
+    /// Creates a new grid with the same dimensions by applying `f` to the values at the given offsets from every position, in row-major order.
+    ///
+    /// The values are given to `f` in the same order as `offsets`, handling positions beyond the edges of the grid
+    /// according to `edge` as in [`Grid::sample`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<bool> = Grid::from_nested_iter([
+    ///     [true, true, true, false],
+    ///     [true, true, true, true],
+    ///     [true, true, true, false],
+    /// ]);
+    ///
+    /// let eroded = grid.stencil(&ORTHOGONAL_ZERO, EdgeMode::Value(false), |values| {
+    ///     values.iter().all(|value| value == &Some(&true))
+    /// });
+    ///
+    /// assert_eq!(eroded.count(|&value| value), 2);
+    /// assert!(eroded[v(1, 1)] && eroded[v(2, 1)]);
+    ///
+    /// let dilated = grid.stencil(&ADJACENT_ZERO, EdgeMode::Skip, |values| {
+    ///     values.iter().any(|value| value == &Some(&true))
+    /// });
+    ///
+    /// assert!(dilated.iter().all(|&value| value));
+    /// ```
+    pub fn stencil<F, U>(&self, offsets: &[Vector], edge: EdgeMode<T>, mut f: F) -> Grid<U>
+    where
+        F: FnMut(&[Option<&T>]) -> U,
+    {
+        let edge = &edge;
+        let mut values = Vec::with_capacity(offsets.len());
+        self.pos_map(|pos, _| {
+            values.clear();
+            values.extend(
+                offsets
+                    .iter()
+                    .map(|&offset| self.sample(pos + offset, edge)),
+            );
+            f(&values)
+        })
+    }
+
+    /// Creates the next generation of a cellular automaton by applying `rule` to every position, in row-major order.
+    ///
+    /// The rule is given the position, its current value and the values of its eight neighbours in the order of
+    /// [`ADJACENT`](crate::prelude::ADJACENT), with `None` for neighbours beyond the edges of the grid.
+    /// Every value is read from `self` rather than the grid being built, so updates never affect their neighbours
+    /// within the same step.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let blinker = Grid::from_str_with(".....\n.....\n.###.\n.....\n.....", |c| c == '#').unwrap();
+    ///
+    /// let life = |grid: &Grid<bool>| {
+    ///     grid.step(|_, &alive, neighbors| {
+    ///         let count = neighbors.iter().filter(|n| n == &&Some(&true)).count();
+    ///         count == 3 || (alive && count == 2)
+    ///     })
+    /// };
+    ///
+    /// let next = life(&blinker);
+    ///
+    /// assert_eq!(next, Grid::from_str_with(".....\n..#..\n..#..\n..#..\n.....", |c| c == '#').unwrap());
+    /// assert_eq!(life(&next), blinker);
+    /// ```
+    pub fn step<F>(&self, rule: F) -> Self
+    where
+        F: FnMut(Vector, &T, &[Option<&T>]) -> T,
+    {
+        self.step_with(&ADJACENT, rule)
+    }
+
+    /// Creates the next generation of a cellular automaton by applying `rule` to every position, in row-major order.
+    ///
+    /// Like [`Grid::step`], but the neighbours passed to the rule are the positions at each of the given offsets,
+    /// in the same order, with `None` for those beyond the edges of the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u32> = Grid::from_nested_iter([[0, 0, 0], [0, 8, 0], [0, 0, 0]]);
+    ///
+    /// let spread = grid.step_with(&ORTHOGONAL, |_, &value, neighbors| {
+    ///     value / 2 + neighbors.iter().flatten().map(|&&n| n / 4).sum::<u32>()
+    /// });
+    ///
+    /// assert_eq!(spread, Grid::from_nested_iter([[0, 2, 0], [2, 4, 2], [0, 2, 0]]));
+    /// ```
+    pub fn step_with<F>(&self, offsets: &[Vector], mut rule: F) -> Self
+    where
+        F: FnMut(Vector, &T, &[Option<&T>]) -> T,
+    {
+        let mut neighbors = Vec::with_capacity(offsets.len());
+        self.pos_map(|pos, value| {
+            neighbors.clear();
+            neighbors.extend(offsets.iter().map(|&offset| self.get(pos + offset)));
+            rule(pos, value, &neighbors)
+        })
+    }
+}
+
+impl Grid<i64> {
+    /// Creates a new grid with the same dimensions by applying a 3x3 weighted kernel centred on every position.
+    ///
+    /// `weights[1][1]` is the weight of the centre, `weights[0][1]` is the weight of the position above it, and so on.
+    /// Positions beyond the edges of the grid are handled according to `edge` as in [`Grid::sample`], with skipped positions contributing nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::from_nested_iter([
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    /// ]);
+    ///
+    /// let box_sum = [[1, 1, 1], [1, 1, 1], [1, 1, 1]];
+    ///
+    /// assert_eq!(
+    ///     grid.convolve_3x3(box_sum, EdgeMode::Skip),
+    ///     Grid::from_nested_iter([[12, 21, 16], [12, 21, 16]])
+    /// );
+    /// assert_eq!(
+    ///     grid.convolve_3x3(box_sum, EdgeMode::Clamp),
+    ///     Grid::from_nested_iter([[21, 27, 33], [30, 36, 42]])
+    /// );
+    ///
+    /// let shift = [[0, 0, 0], [0, 0, 1], [0, 0, 0]];
+    ///
+    /// assert_eq!(
+    ///     grid.convolve_3x3(shift, EdgeMode::Wrap),
+    ///     Grid::from_nested_iter([[2, 3, 1], [5, 6, 4]])
+    /// );
+    /// ```
+    pub fn convolve_3x3(&self, weights: [[i64; 3]; 3], edge: EdgeMode<i64>) -> Self {
+        self.convolve(edge, |_, sample| {
+            let mut sum = 0;
+            for (dy, row) in (-1..=1).zip(weights) {
+                for (dx, weight) in (-1..=1).zip(row) {
+                    if let Some(value) = sample(Vector::new(dx, dy)) {
+                        sum += weight * value;
+                    }
+                }
+            }
+            sum
+        })
+    }
+}
@@ -0,0 +1,70 @@
+//! Error types for fallible `Grid` operations.
+
+use crate::vector::Vector;
+
+use std::{error::Error, fmt};
+
+/// An error returned by the fallible `Grid` constructors and methods.
+///
+/// # Examples
+///
+/// ```
+/// use grid::prelude::*;
+///
+/// let result: Result<Grid<u8>, GridError> = Grid::try_new(0, 3, 1);
+///
+/// assert_eq!(
+///     result,
+///     Err(GridError::NonPositiveDimensions {
+///         width: 0,
+///         height: 3
+///     })
+/// );
+/// ```
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub enum GridError {
+    /// At least one of the dimensions was zero or negative.
+    NonPositiveDimensions { width: i64, height: i64 },
+    /// The number of cells in a grid with the given dimensions does not fit in a `usize`.
+    CapacityOverflow { width: i64, height: i64 },
+    /// The row at index `row` had `found` values where `expected` values were required.
+    RaggedRows { expected: i64, found: i64, row: i64 },
+    /// The position `pos` was outside the bounds of a grid with dimensions `dim`.
+    OutOfBounds { pos: Vector, dim: Vector },
+    /// There were `found` values where `expected` values were required to fill a grid.
+    LengthMismatch { expected: usize, found: usize },
+    /// The input to parse a grid from contained no rows.
+    EmptyInput,
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NonPositiveDimensions { width, height } => {
+                write!(f, "dimensions must be positive: ({width}, {height})")
+            }
+            Self::CapacityOverflow { width, height } => {
+                write!(f, "dimensions are too large: ({width}, {height})")
+            }
+            Self::RaggedRows {
+                expected,
+                found,
+                row,
+            } => {
+                write!(f, "row {row} has length {found} but expected {expected}")
+            }
+            Self::OutOfBounds { pos, dim } => {
+                write!(
+                    f,
+                    "position out of bounds: the dimensions are {dim} but the position is {pos}"
+                )
+            }
+            Self::LengthMismatch { expected, found } => {
+                write!(f, "expected {expected} values but found {found}")
+            }
+            Self::EmptyInput => write!(f, "input contains no rows"),
+        }
+    }
+}
+
+impl Error for GridError {}
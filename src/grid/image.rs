@@ -0,0 +1,73 @@
+//! Conversions between a `Grid` and an `image::RgbImage`, enabled by the `image` feature.
+
+use crate::{grid::Grid, vector::Vector};
+
+use image::{Rgb, RgbImage};
+
+impl<T> Grid<T> {
+    /// Creates an image with the same dimensions as the grid, mapping the value at each position to the colour of the pixel at that position.
+    ///
+    /// Panics if the width or height of the grid is greater than `u32::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid = Grid::from_nested_iter([[true, false, false], [false, false, true]]);
+    ///
+    /// let img = grid.to_image(|&wall| if wall { [0, 0, 0] } else { [255, 255, 255] });
+    ///
+    /// assert_eq!(img.dimensions(), (3, 2));
+    /// assert_eq!(img.get_pixel(2, 1).0, [0, 0, 0]);
+    /// assert_eq!(img.get_pixel(1, 0).0, [255, 255, 255]);
+    /// ```
+    #[track_caller]
+    pub fn to_image<F>(&self, mut f: F) -> RgbImage
+    where
+        F: FnMut(&T) -> [u8; 3],
+    {
+        let (Ok(width), Ok(height)) = (u32::try_from(self.width()), u32::try_from(self.height()))
+        else {
+            panic!("grid dimensions {} exceed the image size limit", self.dim);
+        };
+        let mut raw = Vec::with_capacity(self.raw.len() * 3);
+        for value in self {
+            raw.extend(f(value));
+        }
+        RgbImage::from_raw(width, height, raw).expect("buffer length matches image dimensions")
+    }
+
+    /// Constructs a new `Grid<T>` with the same dimensions as the image, mapping the colour of each pixel to the value at that position.
+    ///
+    /// Panics if the image is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    /// use image::{Rgb, RgbImage};
+    ///
+    /// let mut img = RgbImage::from_pixel(4, 3, Rgb([255, 255, 255]));
+    /// img.put_pixel(1, 2, Rgb([0, 0, 0]));
+    ///
+    /// let grid: Grid<bool> = Grid::from_image(&img, |[r, g, b]| r == 0 && g == 0 && b == 0);
+    ///
+    /// assert_eq!(grid.dim(), v(4, 3));
+    /// assert_eq!(grid.count(|&wall| wall), 1);
+    /// assert!(grid[v(1, 2)]);
+    ///
+    /// assert_eq!(grid.to_image(|&wall| if wall { [0; 3] } else { [255; 3] }), img);
+    /// ```
+    #[track_caller]
+    pub fn from_image<F>(img: &RgbImage, mut f: F) -> Self
+    where
+        F: FnMut([u8; 3]) -> T,
+    {
+        let (width, height) = img.dimensions();
+        Self::from_fn(width as i64, height as i64, |pos: Vector| {
+            let Rgb(pixel) = *img.get_pixel(pos.x as u32, pos.y as u32);
+            f(pixel)
+        })
+    }
+}
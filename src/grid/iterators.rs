@@ -1,10 +1,16 @@
-//! Iterator types for iterating over a `Grid` and its positions.
+//! Iterator types for iterating over a `Grid`, its positions, rows, columns, diagonals, lines and neighbours.
 
-use crate::{grid::Grid, vector::Vector};
+use crate::{
+    grid::Grid,
+    vector::{
+        constants::{ADJACENT, DIAGONAL, ORTHOGONAL},
+        Vector,
+    },
+};
 
 use std::{
-    iter::Zip,
-    slice::{Iter, IterMut},
+    iter::{Rev, Zip},
+    slice::{ChunksExactMut, Iter, IterMut},
     vec::IntoIter,
 };
 
@@ -33,7 +39,7 @@ impl<T> Grid<T> {
     /// assert_eq!(grid.iter().sum::<u8>(), 51);
     /// ```
     #[inline(always)]
-    pub fn iter(&self) -> Iter<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
         self.raw.iter()
     }
 
@@ -60,10 +66,28 @@ impl<T> Grid<T> {
     /// assert_eq!(grid[v(3, 5)], 1);
     /// ```
     #[inline(always)]
-    pub fn iter_mut(&mut self) -> IterMut<T> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
         self.raw.iter_mut()
     }
 
+    /// Returns an iterator over references to the values in the grid, in reverse row-major order.
+    ///
+    /// The rows are visited from bottom to top, and the values in each row from right to left.
+    /// This is equivalent to `grid.iter().rev()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::from_nested_iter([[1, 2], [3, 4]]);
+    ///
+    /// assert_eq!(grid.iter_rev().copied().collect::<Vec<_>>(), vec![4, 3, 2, 1]);
+    /// ```
+    pub fn iter_rev(&self) -> Rev<Iter<'_, T>> {
+        self.raw.iter().rev()
+    }
+
     /// Returns an iterator over every position that can be used to index into the grid, in row-major order.
     ///
     /// # Examples
@@ -90,12 +114,383 @@ impl<T> Grid<T> {
     /// }
     /// ```
     pub fn positions(&self) -> Positions {
-        Positions {
-            pos: Vector::new(0, 0),
+        Positions::new(self.dim)
+    }
+
+    /// Returns an iterator over the positions strictly inside the outer edge of the grid, in row-major order.
+    ///
+    /// Yields nothing if the grid is narrower or shorter than 3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::new(4, 3, 0);
+    ///
+    /// assert_eq!(grid.interior_positions().collect::<Vec<_>>(), vec![v(1, 1), v(2, 1)]);
+    /// assert_eq!(Grid::new(2, 5, 0).interior_positions().next(), None);
+    ///
+    /// for (w, h) in [(1, 1), (2, 3), (3, 3), (5, 4), (7, 2)] {
+    ///     let grid = Grid::new(w, h, 0);
+    ///     let on_edge = |pos: Vector| pos.x == 0 || pos.y == 0 || pos.x == w - 1 || pos.y == h - 1;
+    ///
+    ///     let interior: Vec<Vector> = grid.interior_positions().collect();
+    ///     let expected: Vec<Vector> = grid.positions().filter(|&pos| !on_edge(pos)).collect();
+    ///
+    ///     assert_eq!(interior, expected);
+    ///     assert_eq!(interior.len() + grid.positions().filter(|&pos| on_edge(pos)).count(), (w * h) as usize);
+    /// }
+    /// ```
+    pub fn interior_positions(&self) -> InteriorPositions {
+        InteriorPositions {
+            inner: Positions::new(Vector::new(
+                (self.width() - 2).max(0),
+                (self.height() - 2).max(0),
+            )),
+        }
+    }
+
+    /// Returns an iterator over the rows of the grid, from top to bottom.
+    ///
+    /// Each row is a slice of length `width`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::from_fn(3, 2, |pos| pos.x + pos.y * 3);
+    ///
+    /// let mut rows = grid.rows();
+    ///
+    /// assert_eq!(rows.len(), 2);
+    /// assert_eq!(rows.next(), Some(&[0, 1, 2][..]));
+    /// assert_eq!(rows.next(), Some(&[3, 4, 5][..]));
+    /// assert_eq!(rows.next(), None);
+    ///
+    /// let sums: Vec<i64> = grid.rows().map(|row| row.iter().sum()).collect();
+    ///
+    /// assert_eq!(sums, [3, 12]);
+    /// ```
+    pub fn rows(&self) -> Rows<'_, T> {
+        Rows {
+            raw: &self.raw,
+            width: self.width() as usize,
+            len: self.height() as usize,
+        }
+    }
+
+    /// Returns an iterator over the rows of the grid and their `y` coordinates, from top to bottom.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<char> = Grid::from_char_str("..#\n#..").unwrap();
+    ///
+    /// let walls: Vec<Vector> = grid
+    ///     .enumerate_rows()
+    ///     .flat_map(|(y, row)| row.iter().position(|&c| c == '#').map(|x| v(x as i64, y)))
+    ///     .collect();
+    ///
+    /// assert_eq!(walls, vec![v(2, 0), v(0, 1)]);
+    /// assert_eq!(grid.enumerate_rows().len(), 2);
+    /// assert_eq!(grid.enumerate_rows().next_back(), Some((1, &['#', '.', '.'][..])));
+    /// ```
+    pub fn enumerate_rows(&self) -> EnumerateRows<'_, T> {
+        EnumerateRows {
+            rows: self.rows(),
+            y: 0,
+        }
+    }
+
+    /// Returns an iterator over mutable references to the rows of the grid and their `y` coordinates, from top to bottom.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<i64> = Grid::new(3, 2, 1);
+    ///
+    /// for (y, row) in grid.enumerate_rows_mut() {
+    ///     row[0] = y * 10;
+    /// }
+    ///
+    /// assert_eq!(grid, Grid::from_nested_iter([[0, 1, 1], [10, 1, 1]]));
+    /// ```
+    pub fn enumerate_rows_mut(&mut self) -> EnumerateRowsMut<'_, T> {
+        let width = self.width().max(1) as usize;
+        EnumerateRowsMut {
+            rows: self.raw.chunks_exact_mut(width),
+            y: 0,
+        }
+    }
+
+    /// Returns an iterator over the anti-diagonals of the grid, where each anti-diagonal contains the positions with the same value of `x + y`.
+    ///
+    /// The anti-diagonals are in order of increasing `x + y`, from `0` to `width + height - 2`.
+    /// Each anti-diagonal is itself an iterator over every position and value on it, in order of increasing `y`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<char> = Grid::from_nested_iter([
+    ///     ['a', 'b', 'c'],
+    ///     ['d', 'e', 'f'],
+    ///     ['g', 'h', 'i'],
+    /// ]);
+    ///
+    /// let diagonals: Vec<String> = grid
+    ///     .diagonals()
+    ///     .map(|diagonal| diagonal.map(|(_, c)| c).collect())
+    ///     .collect();
+    ///
+    /// assert_eq!(diagonals, ["a", "bd", "ceg", "fh", "i"]);
+    ///
+    /// for (sum, diagonal) in grid.diagonals().enumerate() {
+    ///     for (pos, _) in diagonal {
+    ///         assert_eq!(pos.x + pos.y, sum as i64);
+    ///     }
+    /// }
+    /// ```
+    pub fn diagonals(&self) -> Diagonals<'_, T> {
+        Diagonals {
+            grid: self,
+            front: 0,
+            back: if self.raw.is_empty() {
+                0
+            } else {
+                self.width() + self.height() - 1
+            },
+        }
+    }
+
+    /// Returns an iterator over the positions on the line from `from` to `to` inclusive, as given by Bresenham's line algorithm.
+    ///
+    /// Positions are yielded even if they are out of bounds, so the caller can decide when to stop with [`Grid::in_bounds`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::new(5, 5, 0);
+    ///
+    /// assert!(grid.line(v(1, 2), v(4, 2)).eq([v(1, 2), v(2, 2), v(3, 2), v(4, 2)]));
+    /// assert!(grid.line(v(3, 3), v(3, 1)).eq([v(3, 3), v(3, 2), v(3, 1)]));
+    /// assert!(grid.line(v(0, 0), v(2, 2)).eq([v(0, 0), v(1, 1), v(2, 2)]));
+    /// assert!(grid.line(v(0, 0), v(4, 2)).eq([v(0, 0), v(1, 1), v(2, 1), v(3, 2), v(4, 2)]));
+    /// assert!(grid.line(v(2, 3), v(2, 3)).eq([v(2, 3)]));
+    /// assert!(grid.line(v(3, 4), v(6, 4)).eq([v(3, 4), v(4, 4), v(5, 4), v(6, 4)]));
+    /// ```
+    pub fn line(&self, from: Vector, to: Vector) -> Line {
+        let delta = (to - from).abs();
+        Line {
+            pos: Some(from),
+            to,
+            step: (to - from).signum(),
+            delta: Vector::new(delta.x, -delta.y),
+            err: delta.x - delta.y,
+        }
+    }
+
+    /// Returns an iterator over the in-bounds positions and values on the line from `from` to `to` inclusive, as given by Bresenham's line algorithm.
+    ///
+    /// Values from this iterator come in the form of a tuple containing the position and a reference to the value:
+    /// `(Vector, &T)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::from_fn(3, 3, |pos| pos.x + pos.y * 3);
+    ///
+    /// let mut values = grid.line_values(v(-1, -1), v(4, 4));
+    ///
+    /// assert_eq!(values.next(), Some((v(0, 0), &0)));
+    /// assert_eq!(values.next(), Some((v(1, 1), &4)));
+    /// assert_eq!(values.next(), Some((v(2, 2), &8)));
+    /// assert_eq!(values.next(), None);
+    /// ```
+    pub fn line_values(&self, from: Vector, to: Vector) -> LineValues<'_, T> {
+        LineValues {
+            grid: self,
+            line: self.line(from, to),
+        }
+    }
+
+    /// Returns an iterator over the rows of the grid, from top to bottom, consuming the grid.
+    ///
+    /// Each row is a `Vec<T>` of length `width`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::from_fn(3, 2, |pos| pos.x + pos.y * 3);
+    ///
+    /// let mut rows = grid.clone().into_rows();
+    ///
+    /// assert_eq!(rows.len(), 2);
+    /// assert_eq!(rows.next(), Some(vec![0, 1, 2]));
+    /// assert_eq!(rows.len(), 1);
+    /// assert_eq!(rows.next(), Some(vec![3, 4, 5]));
+    /// assert_eq!(rows.next(), None);
+    ///
+    /// assert_eq!(Grid::from_rows(grid.clone().into_rows()), Ok(grid));
+    /// ```
+    pub fn into_rows(self) -> IntoRows<T> {
+        IntoRows {
+            width: self.width() as usize,
+            len: self.height() as usize,
+            iter: self.raw.into_iter(),
+        }
+    }
+
+    /// Returns an iterator over the columns of the grid, from left to right.
+    ///
+    /// Each column is itself an iterator over references to its values, from top to bottom.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::from_fn(3, 2, |pos| pos.x + pos.y * 3);
+    ///
+    /// let mut cols = grid.cols();
+    ///
+    /// assert_eq!(cols.len(), 3);
+    /// assert!(cols.next().unwrap().eq(&[0, 3]));
+    /// assert!(cols.next().unwrap().eq(&[1, 4]));
+    /// assert!(cols.next().unwrap().eq(&[2, 5]));
+    /// assert!(cols.next().is_none());
+    ///
+    /// let sums: Vec<i64> = grid.cols().map(|col| col.copied().sum()).collect();
+    ///
+    /// assert_eq!(sums, [3, 5, 7]);
+    /// ```
+    pub fn cols(&self) -> Columns<'_, T> {
+        Columns {
+            raw: &self.raw,
+            width: self.width() as usize,
+            height: self.height() as usize,
+            front: 0,
+            back: self.width() as usize,
+        }
+    }
+
+    /// Returns an iterator over the in-bounds positions `pos + offset` for each of the given offsets, in order.
+    ///
+    /// Unlike [`Grid::orthogonal_neighbors`] and similar methods, this does not borrow the grid, so the grid can be modified while iterating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<u8> = Grid::new(3, 3, 0);
+    ///
+    /// let mut neighbors = grid.neighbors(v(0, 1), &ORTHOGONAL);
+    ///
+    /// assert_eq!(neighbors.next(), Some(v(1, 1)));
+    /// assert_eq!(neighbors.next(), Some(v(0, 0)));
+    /// assert_eq!(neighbors.next(), Some(v(0, 2)));
+    /// assert_eq!(neighbors.next(), None);
+    ///
+    /// for pos in grid.neighbors(v(2, 2), &ADJACENT_ZERO) {
+    ///     grid[pos] += 1;
+    /// }
+    ///
+    /// assert_eq!(grid, Grid::from_nested_iter([[0, 0, 0], [0, 1, 1], [0, 1, 1]]));
+    /// ```
+    pub fn neighbors<'a>(&self, pos: Vector, offsets: &'a [Vector]) -> NeighborPositions<'a> {
+        NeighborPositions {
+            pos,
             dim: self.dim,
+            offsets: offsets.iter(),
         }
     }
 
+    /// Returns an iterator over the in-bounds orthogonal neighbours of the given position, in the order of [`ORTHOGONAL`].
+    ///
+    /// Values from this iterator come in the form of a tuple containing the position and a reference to the value:
+    /// `(Vector, &T)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::from_fn(3, 3, |pos| pos.x + pos.y * 3);
+    ///
+    /// let mut neighbors = grid.orthogonal_neighbors(v(0, 0));
+    ///
+    /// assert_eq!(neighbors.next(), Some((v(1, 0), &1)));
+    /// assert_eq!(neighbors.next(), Some((v(0, 1), &3)));
+    /// assert_eq!(neighbors.next(), None);
+    ///
+    /// assert_eq!(grid.orthogonal_neighbors(v(1, 1)).count(), 4);
+    /// ```
+    pub fn orthogonal_neighbors(&self, pos: Vector) -> Neighbors<'_, T> {
+        Neighbors::new(self, pos, &ORTHOGONAL)
+    }
+
+    /// Returns an iterator over the in-bounds diagonal neighbours of the given position, in the order of [`DIAGONAL`].
+    ///
+    /// Values from this iterator come in the form of a tuple containing the position and a reference to the value:
+    /// `(Vector, &T)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::from_fn(3, 3, |pos| pos.x + pos.y * 3);
+    ///
+    /// let mut neighbors = grid.diagonal_neighbors(v(2, 0));
+    ///
+    /// assert_eq!(neighbors.next(), Some((v(1, 1), &4)));
+    /// assert_eq!(neighbors.next(), None);
+    ///
+    /// assert_eq!(grid.diagonal_neighbors(v(1, 1)).count(), 4);
+    /// ```
+    pub fn diagonal_neighbors(&self, pos: Vector) -> Neighbors<'_, T> {
+        Neighbors::new(self, pos, &DIAGONAL)
+    }
+
+    /// Returns an iterator over the in-bounds orthogonal and diagonal neighbours of the given position, in the order of [`ADJACENT`].
+    ///
+    /// Values from this iterator come in the form of a tuple containing the position and a reference to the value:
+    /// `(Vector, &T)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::from_fn(3, 3, |pos| pos.x + pos.y * 3);
+    ///
+    /// let mut neighbors = grid.adjacent_neighbors(v(0, 2));
+    ///
+    /// assert_eq!(neighbors.next(), Some((v(1, 2), &7)));
+    /// assert_eq!(neighbors.next(), Some((v(1, 1), &4)));
+    /// assert_eq!(neighbors.next(), Some((v(0, 1), &3)));
+    /// assert_eq!(neighbors.next(), None);
+    ///
+    /// assert_eq!(grid.adjacent_neighbors(v(1, 1)).count(), 8);
+    /// ```
+    pub fn adjacent_neighbors(&self, pos: Vector) -> Neighbors<'_, T> {
+        Neighbors::new(self, pos, &ADJACENT)
+    }
+
     /// Returns an iterator over every position and value in the grid, in row-major order.
     ///
     /// Values from this iterator come in the form of a tuple containing the position and a reference to the value:
@@ -113,10 +508,40 @@ impl<T> Grid<T> {
     ///     assert_eq!(*value, pos.x * 2 + pos.y);
     /// }
     /// ```
-    pub fn iter_positions(&self) -> PositionIter<T> {
+    pub fn iter_positions(&self) -> PositionIter<'_, T> {
         PositionIter::new(self.positions().zip(self.iter()))
     }
 
+    /// Returns an iterator over every position and value in the grid, in column-major order.
+    ///
+    /// The columns are visited from left to right, and the values in each column from top to bottom.
+    ///
+    /// Values from this iterator come in the form of a tuple containing the position and a reference to the value:
+    /// `(Vector, &T)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::from_nested_iter([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// let mut iter = grid.iter_col_major();
+    ///
+    /// assert_eq!(iter.next(), Some((v(0, 0), &1)));
+    /// assert_eq!(iter.next(), Some((v(0, 1), &4)));
+    /// assert_eq!(iter.next(), Some((v(1, 0), &2)));
+    /// assert_eq!(iter.next_back(), Some((v(2, 1), &6)));
+    /// assert_eq!(iter.len(), 2);
+    /// ```
+    pub fn iter_col_major(&self) -> ColMajorPositionIter<'_, T> {
+        ColMajorPositionIter {
+            grid: self,
+            front: 0,
+            back: self.raw.len(),
+        }
+    }
+
     /// Returns an iterator over every position and value in the grid, in row-major order.
     ///
     /// Values from this iterator come in the form of a tuple containing the position and a mutable reference to the value:
@@ -136,7 +561,7 @@ impl<T> Grid<T> {
     /// assert_eq!(grid[v(2, 3)], 6);
     /// assert_eq!(grid[v(7, 9)], 63);
     /// ```
-    pub fn iter_mut_positions(&mut self) -> PositionIterMut<T> {
+    pub fn iter_mut_positions(&mut self) -> PositionIterMut<'_, T> {
         PositionIterMut::new(self.positions().zip(self.iter_mut()))
     }
 
@@ -158,7 +583,7 @@ impl<T> Grid<T> {
     /// }
     /// ```
     pub fn into_iter_positions(self) -> PositionIntoIter<T> {
-        PositionIntoIter::new(self.positions().zip(self.into_iter()))
+        PositionIntoIter::new(self.positions().zip(self))
     }
 }
 
@@ -275,42 +700,725 @@ impl<'a, T> IntoIterator for &'a mut Grid<T> {
 /// ```
 #[derive(Clone, Copy)]
 pub struct Positions {
-    pos: Vector,
-    dim: Vector,
+    front: usize,
+    back: usize,
+    width: usize,
+}
+
+impl Positions {
+    #[inline(always)]
+    pub(crate) fn new(dim: Vector) -> Self {
+        Self {
+            front: 0,
+            back: (dim.x * dim.y) as usize,
+            width: dim.x as usize,
+        }
+    }
+
+    #[inline(always)]
+    fn pos_at(&self, index: usize) -> Vector {
+        Vector::new((index % self.width) as i64, (index / self.width) as i64)
+    }
 }
 
 impl Iterator for Positions {
     type Item = Vector;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.pos.y != self.dim.y {
-            let pos = self.pos;
-            self.pos.x += 1;
-            if self.pos.x == self.dim.x {
-                self.pos.x = 0;
-                self.pos.y += 1;
-            }
-            return Some(pos);
+        if self.front == self.back {
+            return None;
         }
-        None
+        let pos = self.pos_at(self.front);
+        self.front += 1;
+        Some(pos)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
     }
 }
 
-/// An iterator over every position and value in the grid, in row-major order.
-///
-/// Values from this iterator come in the form of a tuple containing the position and a reference to the value:
-/// `(Vector, &T)`
+impl DoubleEndedIterator for Positions {
+    /// Returns the next position in reverse row-major order, starting from the bottom-right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::new(3, 2, 0);
+    ///
+    /// let mut pos = grid.positions().rev();
+    ///
+    /// assert_eq!(pos.next(), Some(v(2, 1)));
+    /// assert_eq!(pos.next(), Some(v(1, 1)));
+    /// assert_eq!(pos.len(), 4);
+    /// assert_eq!(grid.positions().len(), 6);
+    ///
+    /// let (pos, value) = grid.iter_positions().next_back().unwrap();
+    ///
+    /// assert_eq!(pos, v(2, 1));
+    /// assert_eq!(*value, 0);
+    /// ```
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.pos_at(self.back))
+    }
+}
+
+impl ExactSizeIterator for Positions {}
+
+/// An iterator over the positions strictly inside the outer edge of the grid, in row-major order.
 ///
 /// # Examples
 ///
 /// ```
 /// use grid::prelude::*;
 ///
-/// let grid: Grid<i64> = Grid::from_fn(8, 10, |pos| pos.x * 2 + pos.y);
+/// let grid: Grid<u8> = Grid::new(4, 4, 0);
 ///
-/// for (pos, value) in grid.iter_positions() {
-///     assert_eq!(grid[pos], *value);
-///     assert_eq!(*value, pos.x * 2 + pos.y);
+/// let mut pos = grid.interior_positions();
+///
+/// assert_eq!(pos.len(), 4);
+/// assert_eq!(pos.next_back(), Some(v(2, 2)));
+/// assert_eq!(pos.next(), Some(v(1, 1)));
+/// ```
+#[derive(Clone, Copy)]
+pub struct InteriorPositions {
+    inner: Positions,
+}
+
+impl Iterator for InteriorPositions {
+    type Item = Vector;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|pos| pos + Vector::new(1, 1))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for InteriorPositions {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|pos| pos + Vector::new(1, 1))
+    }
+}
+
+impl ExactSizeIterator for InteriorPositions {}
+
+/// An iterator over the rows of the grid, from top to bottom.
+///
+/// Each row is a slice of length `width`.
+///
+/// # Examples
+///
+/// ```
+/// use grid::prelude::*;
+///
+/// let grid: Grid<i64> = Grid::from_fn(3, 2, |pos| pos.x + pos.y * 3);
+///
+/// for (y, row) in grid.rows().enumerate() {
+///     assert_eq!(row.len(), 3);
+///     assert_eq!(row[1], grid[v(1, y as i64)]);
+/// }
+/// ```
+pub struct Rows<'a, T> {
+    raw: &'a [T],
+    width: usize,
+    len: usize,
+}
+
+impl<'a, T> Iterator for Rows<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let (row, rest) = self.raw.split_at(self.width);
+        self.raw = rest;
+        self.len -= 1;
+        Some(row)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Rows<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let (rest, row) = self.raw.split_at(self.raw.len() - self.width);
+        self.raw = rest;
+        self.len -= 1;
+        Some(row)
+    }
+}
+
+impl<T> ExactSizeIterator for Rows<'_, T> {}
+
+/// An iterator over the rows of the grid and their `y` coordinates, from top to bottom.
+///
+/// Values from this iterator come in the form of a tuple containing the `y` coordinate and the row: `(i64, &[T])`
+///
+/// # Examples
+///
+/// ```
+/// use grid::prelude::*;
+///
+/// let grid: Grid<i64> = Grid::from_fn(2, 3, |pos| pos.x + pos.y * 2);
+///
+/// let mut rows = grid.enumerate_rows();
+///
+/// assert_eq!(rows.next(), Some((0, &[0, 1][..])));
+/// assert_eq!(rows.next_back(), Some((2, &[4, 5][..])));
+/// assert_eq!(rows.next(), Some((1, &[2, 3][..])));
+/// assert_eq!(rows.next(), None);
+/// ```
+pub struct EnumerateRows<'a, T> {
+    rows: Rows<'a, T>,
+    y: i64,
+}
+
+impl<'a, T> Iterator for EnumerateRows<'a, T> {
+    type Item = (i64, &'a [T]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let row = self.rows.next()?;
+        self.y += 1;
+        Some((self.y - 1, row))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rows.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for EnumerateRows<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let row = self.rows.next_back()?;
+        Some((self.y + self.rows.len() as i64, row))
+    }
+}
+
+impl<T> ExactSizeIterator for EnumerateRows<'_, T> {}
+
+/// An iterator over mutable references to the rows of the grid and their `y` coordinates, from top to bottom.
+///
+/// Values from this iterator come in the form of a tuple containing the `y` coordinate and the row: `(i64, &mut [T])`
+pub struct EnumerateRowsMut<'a, T> {
+    rows: ChunksExactMut<'a, T>,
+    y: i64,
+}
+
+impl<'a, T> Iterator for EnumerateRowsMut<'a, T> {
+    type Item = (i64, &'a mut [T]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let row = self.rows.next()?;
+        self.y += 1;
+        Some((self.y - 1, row))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rows.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for EnumerateRowsMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let row = self.rows.next_back()?;
+        Some((self.y + self.rows.len() as i64, row))
+    }
+}
+
+impl<T> ExactSizeIterator for EnumerateRowsMut<'_, T> {}
+
+/// An iterator over the anti-diagonals of the grid, in order of increasing `x + y`.
+///
+/// Each anti-diagonal is a [`Diagonal`], which is itself an iterator over every position and value on it, in order of increasing `y`.
+///
+/// # Examples
+///
+/// ```
+/// use grid::prelude::*;
+///
+/// let grid: Grid<u8> = Grid::new(4, 2, 0);
+///
+/// let lengths: Vec<usize> = grid.diagonals().map(|diagonal| diagonal.len()).collect();
+///
+/// assert_eq!(lengths, [1, 2, 2, 2, 1]);
+/// ```
+pub struct Diagonals<'a, T> {
+    grid: &'a Grid<T>,
+    front: i64,
+    back: i64,
+}
+
+impl<'a, T> Diagonals<'a, T> {
+    fn diagonal(&self, sum: i64) -> Diagonal<'a, T> {
+        Diagonal {
+            grid: self.grid,
+            sum,
+            front: (sum - self.grid.width() + 1).max(0),
+            back: sum.min(self.grid.height() - 1) + 1,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Diagonals<'a, T> {
+    type Item = Diagonal<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        let diagonal = self.diagonal(self.front);
+        self.front += 1;
+        Some(diagonal)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front) as usize;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Diagonals<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.diagonal(self.back))
+    }
+}
+
+impl<T> ExactSizeIterator for Diagonals<'_, T> {}
+
+/// An iterator over every position and value on a single anti-diagonal of the grid, in order of increasing `y`.
+///
+/// Values from this iterator come in the form of a tuple containing the position and a reference to the value:
+/// `(Vector, &T)`
+///
+/// # Examples
+///
+/// ```
+/// use grid::prelude::*;
+///
+/// let grid: Grid<i64> = Grid::from_fn(3, 3, |pos| pos.x * 10 + pos.y);
+///
+/// let mut diagonal = grid.diagonals().nth(2).unwrap();
+///
+/// assert_eq!(diagonal.next(), Some((v(2, 0), &20)));
+/// assert_eq!(diagonal.next(), Some((v(1, 1), &11)));
+/// assert_eq!(diagonal.next(), Some((v(0, 2), &2)));
+/// assert_eq!(diagonal.next(), None);
+/// ```
+pub struct Diagonal<'a, T> {
+    grid: &'a Grid<T>,
+    sum: i64,
+    front: i64,
+    back: i64,
+}
+
+impl<'a, T> Iterator for Diagonal<'a, T> {
+    type Item = (Vector, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        let pos = Vector::new(self.sum - self.front, self.front);
+        self.front += 1;
+        Some((pos, &self.grid[pos]))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front) as usize;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Diagonal<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        let pos = Vector::new(self.sum - self.back, self.back);
+        Some((pos, &self.grid[pos]))
+    }
+}
+
+impl<T> ExactSizeIterator for Diagonal<'_, T> {}
+
+/// An iterator over the positions on a line between two positions inclusive, as given by Bresenham's line algorithm.
+///
+/// # Examples
+///
+/// ```
+/// use grid::prelude::*;
+///
+/// let grid: Grid<u8> = Grid::new(8, 8, 0);
+///
+/// let line: Vec<Vector> = grid.line(v(7, 1), v(1, 4)).collect();
+///
+/// assert_eq!(line.first(), Some(&v(7, 1)));
+/// assert_eq!(line.last(), Some(&v(1, 4)));
+/// assert_eq!(line.len(), 7);
+/// ```
+#[derive(Clone, Copy)]
+pub struct Line {
+    pos: Option<Vector>,
+    to: Vector,
+    step: Vector,
+    delta: Vector,
+    err: i64,
+}
+
+impl Iterator for Line {
+    type Item = Vector;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pos = self.pos?;
+        if pos == self.to {
+            self.pos = None;
+            return Some(pos);
+        }
+        let mut next = pos;
+        let e2 = 2 * self.err;
+        if e2 >= self.delta.y {
+            self.err += self.delta.y;
+            next.x += self.step.x;
+        }
+        if e2 <= self.delta.x {
+            self.err += self.delta.x;
+            next.y += self.step.y;
+        }
+        self.pos = Some(next);
+        Some(pos)
+    }
+}
+
+/// An iterator over the in-bounds positions and values on a line between two positions inclusive, as given by Bresenham's line algorithm.
+///
+/// Values from this iterator come in the form of a tuple containing the position and a reference to the value:
+/// `(Vector, &T)`
+///
+/// # Examples
+///
+/// ```
+/// use grid::prelude::*;
+///
+/// let grid: Grid<u8> = Grid::new(4, 4, 1);
+///
+/// assert_eq!(grid.line_values(v(0, 0), v(3, 0)).map(|(_, value)| value).sum::<u8>(), 4);
+/// ```
+pub struct LineValues<'a, T> {
+    grid: &'a Grid<T>,
+    line: Line,
+}
+
+impl<'a, T> Iterator for LineValues<'a, T> {
+    type Item = (Vector, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for pos in self.line.by_ref() {
+            if let Some(value) = self.grid.get(pos) {
+                return Some((pos, value));
+            }
+        }
+        None
+    }
+}
+
+/// An iterator over the rows of the grid, from top to bottom, consuming the grid.
+///
+/// Each row is a `Vec<T>` of length `width`.
+///
+/// # Examples
+///
+/// ```
+/// use grid::prelude::*;
+///
+/// let grid: Grid<u8> = Grid::new(4, 3, 1);
+///
+/// for row in grid.into_rows() {
+///     assert_eq!(row, [1, 1, 1, 1]);
+/// }
+/// ```
+pub struct IntoRows<T> {
+    iter: IntoIter<T>,
+    width: usize,
+    len: usize,
+}
+
+impl<T> Iterator for IntoRows<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        Some(self.iter.by_ref().take(self.width).collect())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for IntoRows<T> {}
+
+/// An iterator over the columns of the grid, from left to right.
+///
+/// Each column is a [`Column`], which is itself an iterator over references to its values, from top to bottom.
+///
+/// # Examples
+///
+/// ```
+/// use grid::prelude::*;
+///
+/// let grid: Grid<i64> = Grid::from_fn(3, 2, |pos| pos.x + pos.y * 3);
+///
+/// for (x, col) in grid.cols().enumerate() {
+///     assert_eq!(col.len(), 2);
+///     assert!(col.eq([&grid[v(x as i64, 0)], &grid[v(x as i64, 1)]]));
+/// }
+/// ```
+pub struct Columns<'a, T> {
+    raw: &'a [T],
+    width: usize,
+    height: usize,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T> Columns<'a, T> {
+    fn column(&self, x: usize) -> Column<'a, T> {
+        Column::new(&self.raw[x..], self.width, self.height)
+    }
+}
+
+impl<'a, T> Iterator for Columns<'a, T> {
+    type Item = Column<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        let col = self.column(self.front);
+        self.front += 1;
+        Some(col)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Columns<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.column(self.back))
+    }
+}
+
+impl<T> ExactSizeIterator for Columns<'_, T> {}
+
+/// An iterator over references to the values in a single column of the grid, from top to bottom.
+///
+/// # Examples
+///
+/// ```
+/// use grid::prelude::*;
+///
+/// let grid: Grid<i64> = Grid::from_fn(3, 4, |pos| pos.x * pos.y);
+///
+/// let col = grid.cols().nth(2).unwrap();
+///
+/// assert_eq!(col.len(), 4);
+/// assert_eq!(col.copied().collect::<Vec<_>>(), [0, 2, 4, 6]);
+/// ```
+pub struct Column<'a, T> {
+    raw: &'a [T],
+    stride: usize,
+    len: usize,
+}
+
+impl<'a, T> Column<'a, T> {
+    #[inline(always)]
+    pub(crate) fn new(raw: &'a [T], stride: usize, len: usize) -> Self {
+        Self { raw, stride, len }
+    }
+}
+
+impl<'a, T> Iterator for Column<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let value = &self.raw[0];
+        self.len -= 1;
+        self.raw = if self.len == 0 {
+            &[]
+        } else {
+            &self.raw[self.stride..]
+        };
+        Some(value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Column<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        Some(&self.raw[self.len * self.stride])
+    }
+}
+
+impl<T> ExactSizeIterator for Column<'_, T> {}
+
+/// An iterator over the in-bounds positions neighbouring a position in the grid, which does not borrow the grid.
+///
+/// # Examples
+///
+/// ```
+/// use grid::prelude::*;
+///
+/// let grid: Grid<u8> = Grid::new(4, 4, 1);
+///
+/// assert_eq!(grid.neighbors(v(0, 0), &ADJACENT).count(), 3);
+/// assert_eq!(grid.neighbors(v(1, 1), &ADJACENT).count(), 8);
+/// ```
+pub struct NeighborPositions<'a> {
+    pos: Vector,
+    dim: Vector,
+    offsets: Iter<'a, Vector>,
+}
+
+impl Iterator for NeighborPositions<'_> {
+    type Item = Vector;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for &offset in self.offsets.by_ref() {
+            let pos = self.pos + offset;
+            if (0..self.dim.x).contains(&pos.x) && (0..self.dim.y).contains(&pos.y) {
+                return Some(pos);
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.offsets.len()))
+    }
+}
+
+/// An iterator over the in-bounds neighbours of a position in the grid.
+///
+/// Values from this iterator come in the form of a tuple containing the position and a reference to the value:
+/// `(Vector, &T)`
+///
+/// # Examples
+///
+/// ```
+/// use grid::prelude::*;
+///
+/// let grid: Grid<u8> = Grid::new(4, 4, 1);
+///
+/// for (pos, value) in grid.adjacent_neighbors(v(3, 3)) {
+///     assert!(grid.in_bounds(pos));
+///     assert_eq!(*value, 1);
+/// }
+/// ```
+pub struct Neighbors<'a, T> {
+    grid: &'a Grid<T>,
+    pos: Vector,
+    offsets: Iter<'a, Vector>,
+}
+
+impl<'a, T> Neighbors<'a, T> {
+    #[inline(always)]
+    fn new(grid: &'a Grid<T>, pos: Vector, offsets: &'a [Vector]) -> Self {
+        Self {
+            grid,
+            pos,
+            offsets: offsets.iter(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for Neighbors<'a, T> {
+    type Item = (Vector, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for &offset in self.offsets.by_ref() {
+            let pos = self.pos + offset;
+            if let Some(value) = self.grid.get(pos) {
+                return Some((pos, value));
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.offsets.len()))
+    }
+}
+
+/// An iterator over every position and value in the grid, in row-major order.
+///
+/// Values from this iterator come in the form of a tuple containing the position and a reference to the value:
+/// `(Vector, &T)`
+///
+/// # Examples
+///
+/// ```
+/// use grid::prelude::*;
+///
+/// let grid: Grid<i64> = Grid::from_fn(8, 10, |pos| pos.x * 2 + pos.y);
+///
+/// for (pos, value) in grid.iter_positions() {
+///     assert_eq!(grid[pos], *value);
+///     assert_eq!(*value, pos.x * 2 + pos.y);
 /// }
 /// ```
 pub struct PositionIter<'a, T> {
@@ -331,8 +1439,88 @@ impl<'a, T> Iterator for PositionIter<'a, T> {
     fn next(&mut self) -> Option<Self::Item> {
         self.iter.next()
     }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for PositionIter<'_, T> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for PositionIter<'_, T> {}
+
+/// An iterator over every position and value in the grid, in column-major order.
+///
+/// Values from this iterator come in the form of a tuple containing the position and a reference to the value:
+/// `(Vector, &T)`
+///
+/// # Examples
+///
+/// ```
+/// use grid::prelude::*;
+///
+/// let grid: Grid<i64> = Grid::from_fn(3, 4, |pos| pos.x * 4 + pos.y);
+///
+/// for (i, (pos, value)) in grid.iter_col_major().enumerate() {
+///     assert_eq!(grid[pos], *value);
+///     assert_eq!(*value, i as i64);
+/// }
+/// ```
+pub struct ColMajorPositionIter<'a, T> {
+    grid: &'a Grid<T>,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T> ColMajorPositionIter<'a, T> {
+    #[inline(always)]
+    fn entry(&self, index: usize) -> (Vector, &'a T) {
+        let height = self.grid.height() as usize;
+        let (x, y) = (index / height, index % height);
+        let grid = self.grid;
+        (
+            Vector::new(x as i64, y as i64),
+            &grid.raw[x + y * grid.width() as usize],
+        )
+    }
+}
+
+impl<'a, T> Iterator for ColMajorPositionIter<'a, T> {
+    type Item = (Vector, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(self.entry(self.front - 1))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for ColMajorPositionIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.entry(self.back))
+    }
 }
 
+impl<T> ExactSizeIterator for ColMajorPositionIter<'_, T> {}
+
 /// An iterator over every position and value in the grid, in row-major order.
 ///
 /// Values from this iterator come in the form of a tuple containing the position and a mutable reference to the value:
@@ -370,8 +1558,22 @@ impl<'a, T> Iterator for PositionIterMut<'a, T> {
     fn next(&mut self) -> Option<Self::Item> {
         self.iter.next()
     }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for PositionIterMut<'_, T> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
 }
 
+impl<T> ExactSizeIterator for PositionIterMut<'_, T> {}
+
 /// An iterator over every position and value in the grid, in row-major order.
 ///
 /// Values from this iterator come in the form of a tuple containing the position and the value:
@@ -407,4 +1609,18 @@ impl<T> Iterator for PositionIntoIter<T> {
     fn next(&mut self) -> Option<Self::Item> {
         self.iter.next()
     }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for PositionIntoIter<T> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
 }
+
+impl<T> ExactSizeIterator for PositionIntoIter<T> {}
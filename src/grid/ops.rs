@@ -0,0 +1,104 @@
+//! Arithmetic operators for grids of numeric values.
+
+use crate::grid::Grid;
+
+use std::ops::{Add, Mul, Sub};
+
+impl<T> Grid<T> {
+    #[track_caller]
+    fn zip_or_panic<F>(&self, other: &Self, f: F) -> Self
+    where
+        F: FnMut(&T, &T) -> T,
+    {
+        match self.zip_with(other, f) {
+            Some(grid) => grid,
+            None => panic!(
+                "grid dimensions do not match: the dimensions are {} and {}",
+                self.dim, other.dim
+            ),
+        }
+    }
+}
+
+/// Adds the values at each position of two grids.
+///
+/// Panics if the grids do not have the same dimensions.
+///
+/// # Examples
+///
+/// ```
+/// use grid::prelude::*;
+///
+/// let grid_a: Grid<i64> = Grid::from_nested_iter([[1, 2], [3, 4]]);
+/// let grid_b: Grid<i64> = Grid::new(2, 2, 10);
+///
+/// assert_eq!(&grid_a + &grid_b, Grid::from_nested_iter([[11, 12], [13, 14]]));
+/// ```
+///
+/// ```should_panic
+/// use grid::prelude::*;
+///
+/// let grid_a: Grid<i64> = Grid::new(2, 3, 0);
+/// let grid_b: Grid<i64> = Grid::new(3, 2, 0);
+///
+/// let _ = &grid_a + &grid_b;
+/// ```
+impl<T> Add for &Grid<T>
+where
+    T: Add<Output = T> + Clone,
+{
+    type Output = Grid<T>;
+
+    #[track_caller]
+    fn add(self, rhs: Self) -> Grid<T> {
+        self.zip_or_panic(rhs, |a, b| a.clone() + b.clone())
+    }
+}
+
+/// Subtracts the values at each position of `rhs` from the values at the same positions of `self`.
+///
+/// Panics if the grids do not have the same dimensions.
+///
+/// # Examples
+///
+/// ```
+/// use grid::prelude::*;
+///
+/// let grid_a: Grid<i64> = Grid::from_nested_iter([[1, 2], [3, 4]]);
+/// let grid_b: Grid<i64> = Grid::from_fn(2, 2, |pos| pos.x);
+///
+/// assert_eq!(&grid_a - &grid_b, Grid::from_nested_iter([[1, 1], [3, 3]]));
+/// ```
+impl<T> Sub for &Grid<T>
+where
+    T: Sub<Output = T> + Clone,
+{
+    type Output = Grid<T>;
+
+    #[track_caller]
+    fn sub(self, rhs: Self) -> Grid<T> {
+        self.zip_or_panic(rhs, |a, b| a.clone() - b.clone())
+    }
+}
+
+/// Multiplies every value in the grid by a scalar.
+///
+/// # Examples
+///
+/// ```
+/// use grid::prelude::*;
+///
+/// let grid: Grid<i64> = Grid::from_nested_iter([[1, -2], [3, 0]]);
+///
+/// assert_eq!(&grid * 3, Grid::from_nested_iter([[3, -6], [9, 0]]));
+/// ```
+impl<T> Mul<T> for &Grid<T>
+where
+    T: Mul<Output = T> + Clone,
+{
+    type Output = Grid<T>;
+
+    fn mul(self, rhs: T) -> Grid<T> {
+        self.map(|value| value.clone() * rhs.clone())
+    }
+}
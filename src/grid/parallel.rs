@@ -0,0 +1,121 @@
+//! Parallel construction, mapping and iteration of a `Grid`, enabled by the `rayon` feature.
+
+use crate::{
+    grid::{size, unwrap, Grid},
+    vector::Vector,
+};
+
+use rayon::prelude::*;
+
+impl<T: Send> Grid<T> {
+    /// Constructs a new `Grid<T>` with the given dimensions, computing all initial values in parallel from the closure `f`
+    /// which maps each position to a value.
+    ///
+    /// The value at each position depends only on that position, so the result is the same as [`Grid::from_fn`]
+    /// regardless of the order in which values are computed.
+    ///
+    /// Panics if the dimensions are not positive or too large.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::par_from_fn(300, 200, |pos| pos.x * pos.y);
+    ///
+    /// assert_eq!(grid, Grid::from_fn(300, 200, |pos| pos.x * pos.y));
+    /// ```
+    #[track_caller]
+    pub fn par_from_fn<F>(width: i64, height: i64, f: F) -> Self
+    where
+        F: Fn(Vector) -> T + Sync + Send,
+    {
+        let size = unwrap(size(width, height));
+        let w = width as usize;
+        let raw = (0..size)
+            .into_par_iter()
+            .map(|i| f(Vector::new((i % w) as i64, (i / w) as i64)))
+            .collect();
+        Self {
+            raw,
+            dim: Vector::new(width, height),
+        }
+    }
+
+    /// Returns a parallel iterator over mutable references to the values in the grid, in row-major order.
+    ///
+    /// Each value is updated independently, so this is only suitable for updates that do not read neighbouring values.
+    /// For updates that depend on neighbours, compute a new grid with [`Grid::step`] or [`Grid::par_from_fn`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut grid: Grid<u32> = Grid::new(64, 64, 3);
+    ///
+    /// grid.par_iter_mut().for_each(|value| *value *= 2);
+    ///
+    /// assert!(grid.iter().all(|&value| value == 6));
+    /// ```
+    pub fn par_iter_mut(&mut self) -> rayon::slice::IterMut<'_, T> {
+        self.raw.par_iter_mut()
+    }
+
+    /// Returns a parallel iterator over every position in the grid and a mutable reference to its value, in row-major order.
+    ///
+    /// Values from this iterator come in the form of a tuple containing the position and a mutable reference to the value:
+    /// `(Vector, &mut T)`, as in [`Grid::iter_mut_positions`].
+    /// Each value is updated independently, so this is only suitable for updates that do not read neighbouring values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut grid: Grid<i64> = Grid::new(50, 40, 1);
+    ///
+    /// grid.par_iter_mut_positions().for_each(|(pos, value)| *value += pos.x * pos.y);
+    ///
+    /// assert_eq!(grid, Grid::from_fn(50, 40, |pos| 1 + pos.x * pos.y));
+    /// ```
+    pub fn par_iter_mut_positions(
+        &mut self,
+    ) -> impl IndexedParallelIterator<Item = (Vector, &mut T)> + '_ {
+        let width = self.width().max(1) as usize;
+        self.raw
+            .par_iter_mut()
+            .enumerate()
+            .map(move |(i, value)| (Vector::new((i % width) as i64, (i / width) as i64), value))
+    }
+}
+
+impl<T: Sync> Grid<T> {
+    /// Maps the values of an existing grid in parallel to create a new grid with the same dimensions.
+    ///
+    /// The result is the same as [`Grid::map`] regardless of the order in which values are computed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u64> = Grid::from_fn(100, 100, |pos| (pos.x + pos.y) as u64);
+    ///
+    /// let squares = grid.par_map(|value| value * value);
+    ///
+    /// assert_eq!(squares, grid.map(|value| value * value));
+    /// ```
+    pub fn par_map<F, U>(&self, f: F) -> Grid<U>
+    where
+        F: Fn(&T) -> U + Sync + Send,
+        U: Send,
+    {
+        Grid {
+            raw: self.raw.par_iter().map(f).collect(),
+            dim: self.dim,
+        }
+    }
+}
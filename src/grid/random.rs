@@ -0,0 +1,63 @@
+//! Random initialisation of a `Grid`, enabled by the `rand` feature.
+
+use crate::grid::Grid;
+
+use rand::{distributions::Standard, prelude::Distribution, Rng};
+
+impl<T> Grid<T> {
+    /// Constructs a new `Grid<T>` with the given dimensions, sampling every value from the [`Standard`] distribution.
+    ///
+    /// Values are sampled in row-major order, so the grid is deterministic for a given seeded `rng`.
+    ///
+    /// Panics if the dimensions are not positive or too large.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let grid_a: Grid<u8> = Grid::random(8, 10, &mut StdRng::seed_from_u64(42));
+    /// let grid_b: Grid<u8> = Grid::random(8, 10, &mut StdRng::seed_from_u64(42));
+    ///
+    /// assert_eq!(grid_a.dim(), v(8, 10));
+    /// assert_eq!(grid_a, grid_b);
+    /// ```
+    #[track_caller]
+    pub fn random<R>(width: i64, height: i64, rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+        Standard: Distribution<T>,
+    {
+        Self::random_with(width, height, rng, |rng| rng.gen())
+    }
+
+    /// Constructs a new `Grid<T>` with the given dimensions, computing every value from the closure `f` which is given `rng`.
+    ///
+    /// Values are computed in row-major order, so the grid is deterministic for a given seeded `rng`.
+    ///
+    /// Panics if the dimensions are not positive or too large.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    /// use rand::{rngs::StdRng, Rng, SeedableRng};
+    ///
+    /// let mut rng = StdRng::seed_from_u64(7);
+    ///
+    /// let cave: Grid<char> = Grid::random_with(20, 10, &mut rng, |rng| {
+    ///     if rng.gen_bool(0.45) { '#' } else { '.' }
+    /// });
+    ///
+    /// assert!(cave.iter().all(|&c| c == '#' || c == '.'));
+    /// ```
+    #[track_caller]
+    pub fn random_with<R, F>(width: i64, height: i64, rng: &mut R, mut f: F) -> Self
+    where
+        R: Rng + ?Sized,
+        F: FnMut(&mut R) -> T,
+    {
+        Self::from_simple_fn(width, height, || f(rng))
+    }
+}
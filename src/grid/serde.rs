@@ -0,0 +1,56 @@
+//! `serde` support for `Grid`, enabled by the `serde` feature.
+
+use crate::{grid::Grid, vector::Vector};
+
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+impl<T: Serialize> Serialize for Grid<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        #[derive(Serialize)]
+        #[serde(rename = "Grid")]
+        struct Data<'a, T> {
+            width: i64,
+            height: i64,
+            data: &'a [T],
+        }
+
+        Data {
+            width: self.width(),
+            height: self.height(),
+            data: &self.raw,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Grid<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(rename = "Grid")]
+        struct Data<T> {
+            width: i64,
+            height: i64,
+            data: Vec<T>,
+        }
+
+        let Data {
+            width,
+            height,
+            data,
+        } = Data::deserialize(deserializer)?;
+        // accept the empty grid that `Grid::from_nested_iter` can construct, so that it round-trips
+        if width == 0 && height == 0 && data.is_empty() {
+            return Ok(Self {
+                raw: data,
+                dim: Vector::new(0, 0),
+            });
+        }
+        Self::from_vec(width, height, data).map_err(D::Error::custom)
+    }
+}
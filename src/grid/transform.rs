@@ -0,0 +1,454 @@
+//! Methods for transposing, rotating, flipping, shifting and scaling a `Grid`.
+
+use crate::{grid::Grid, vector::Vector};
+
+impl<T: Clone> Grid<T> {
+    /// Returns a new grid with the rows and columns of `self` swapped, so that the value at `(x, y)` moves to `(y, x)`.
+    ///
+    /// The returned grid has dimensions `height` by `width`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::from_fn(3, 2, |pos| pos.x + pos.y * 3);
+    ///
+    /// let transposed = grid.transpose();
+    ///
+    /// assert_eq!(transposed.dim(), v(2, 3));
+    /// assert_eq!(transposed[v(1, 0)], grid[v(0, 1)]);
+    /// assert_eq!(transposed[v(0, 2)], grid[v(2, 0)]);
+    /// assert_eq!(transposed.transpose(), grid);
+    ///
+    /// let row: Grid<u8> = Grid::from_iter(4, 1, [1, 2, 3, 4]);
+    ///
+    /// assert_eq!(row.transpose(), Grid::from_iter(1, 4, [1, 2, 3, 4]));
+    /// ```
+    pub fn transpose(&self) -> Self {
+        let mut raw = Vec::with_capacity(self.raw.len());
+        for x in 0..self.width() {
+            for y in 0..self.height() {
+                raw.push(self[Vector::new(x, y)].clone());
+            }
+        }
+        Self {
+            raw,
+            dim: Vector::new(self.height(), self.width()),
+        }
+    }
+
+    /// Returns a new grid equal to `self` rotated 90 degrees clockwise.
+    ///
+    /// The returned grid has dimensions `height` by `width`, and the value at `(x, y)` in the new grid comes from `(y, height - 1 - x)` in `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<char> = Grid::from_nested_iter([
+    ///     ['a', 'b'],
+    ///     ['c', 'd'],
+    ///     ['e', 'f'],
+    /// ]);
+    ///
+    /// let rotated = grid.rotate_cw();
+    ///
+    /// assert_eq!(
+    ///     rotated,
+    ///     Grid::from_nested_iter([
+    ///         ['e', 'c', 'a'],
+    ///         ['f', 'd', 'b'],
+    ///     ])
+    /// );
+    /// assert_eq!(rotated.rotate_cw().rotate_cw().rotate_cw(), grid);
+    /// ```
+    pub fn rotate_cw(&self) -> Self {
+        let mut raw = Vec::with_capacity(self.raw.len());
+        for y in 0..self.width() {
+            for x in 0..self.height() {
+                raw.push(self[Vector::new(y, self.height() - 1 - x)].clone());
+            }
+        }
+        Self {
+            raw,
+            dim: Vector::new(self.height(), self.width()),
+        }
+    }
+
+    /// Returns a new grid equal to `self` rotated 90 degrees counter-clockwise.
+    ///
+    /// The returned grid has dimensions `height` by `width`, and the value at `(x, y)` in the new grid comes from `(width - 1 - y, x)` in `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<char> = Grid::from_nested_iter([
+    ///     ['a', 'b'],
+    ///     ['c', 'd'],
+    ///     ['e', 'f'],
+    /// ]);
+    ///
+    /// let rotated = grid.rotate_ccw();
+    ///
+    /// assert_eq!(
+    ///     rotated,
+    ///     Grid::from_nested_iter([
+    ///         ['b', 'd', 'f'],
+    ///         ['a', 'c', 'e'],
+    ///     ])
+    /// );
+    /// assert_eq!(rotated.rotate_cw(), grid);
+    /// ```
+    pub fn rotate_ccw(&self) -> Self {
+        let mut raw = Vec::with_capacity(self.raw.len());
+        for y in 0..self.width() {
+            for x in 0..self.height() {
+                raw.push(self[Vector::new(self.width() - 1 - y, x)].clone());
+            }
+        }
+        Self {
+            raw,
+            dim: Vector::new(self.height(), self.width()),
+        }
+    }
+
+    /// Returns a new grid equal to `self` rotated 180 degrees.
+    ///
+    /// The returned grid has the same dimensions as `self`, and the value at `(x, y)` in the new grid comes from `(width - 1 - x, height - 1 - y)` in `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<char> = Grid::from_nested_iter([
+    ///     ['a', 'b'],
+    ///     ['c', 'd'],
+    ///     ['e', 'f'],
+    /// ]);
+    ///
+    /// let rotated = grid.rotate_180();
+    ///
+    /// assert_eq!(
+    ///     rotated,
+    ///     Grid::from_nested_iter([
+    ///         ['f', 'e'],
+    ///         ['d', 'c'],
+    ///         ['b', 'a'],
+    ///     ])
+    /// );
+    /// assert_eq!(rotated, grid.rotate_cw().rotate_cw());
+    /// ```
+    pub fn rotate_180(&self) -> Self {
+        Self {
+            raw: self.raw.iter().rev().cloned().collect(),
+            dim: self.dim,
+        }
+    }
+
+    /// Returns a new grid equal to `self` mirrored left-to-right.
+    ///
+    /// See [`Grid::flip_horizontal`] for the in-place version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::from_nested_iter([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// assert_eq!(
+    ///     grid.flipped_horizontal(),
+    ///     Grid::from_nested_iter([[3, 2, 1], [6, 5, 4]])
+    /// );
+    /// ```
+    pub fn flipped_horizontal(&self) -> Self {
+        let mut grid = self.clone();
+        grid.flip_horizontal();
+        grid
+    }
+
+    /// Returns a new grid equal to `self` mirrored top-to-bottom.
+    ///
+    /// See [`Grid::flip_vertical`] for the in-place version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::from_nested_iter([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// assert_eq!(
+    ///     grid.flipped_vertical(),
+    ///     Grid::from_nested_iter([[4, 5, 6], [1, 2, 3]])
+    /// );
+    /// ```
+    pub fn flipped_vertical(&self) -> Self {
+        let mut grid = self.clone();
+        grid.flip_vertical();
+        grid
+    }
+
+    /// Returns a new grid with every value of `self` moved from `pos` to `pos + offset`.
+    ///
+    /// Values moved beyond the edges of the grid are discarded, and positions left without a value are set to `fill`.
+    /// An offset of [`EAST`](crate::prelude::EAST) moves values right and [`SOUTH`](crate::prelude::SOUTH) moves them down.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::from_nested_iter([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// assert_eq!(grid.shifted(EAST, 0), Grid::from_nested_iter([[0, 1, 2], [0, 4, 5]]));
+    /// assert_eq!(grid.shifted(v(-2, 1), 0), Grid::from_nested_iter([[0, 0, 0], [3, 0, 0]]));
+    /// assert_eq!(grid.shifted(v(0, 50), 9), Grid::new(3, 2, 9));
+    /// assert_eq!(grid.shifted(ZERO, 0), grid);
+    /// ```
+    pub fn shifted(&self, offset: Vector, fill: T) -> Self {
+        if offset.x.unsigned_abs() >= self.width() as u64
+            || offset.y.unsigned_abs() >= self.height() as u64
+        {
+            return Self {
+                raw: vec![fill; self.raw.len()],
+                dim: self.dim,
+            };
+        }
+        self.pos_map(|pos, _| self.get(pos - offset).unwrap_or(&fill).clone())
+    }
+
+    /// Moves every value in the grid from `pos` to `pos + offset`.
+    ///
+    /// Values moved beyond the edges of the grid are discarded, and positions left without a value are set to `fill`.
+    /// An offset of [`EAST`](crate::prelude::EAST) moves values right and [`SOUTH`](crate::prelude::SOUTH) moves them down.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<u8> = Grid::from_nested_iter([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// grid.shift(NW, 0);
+    ///
+    /// assert_eq!(grid, Grid::from_nested_iter([[5, 6, 0], [0, 0, 0]]));
+    /// ```
+    pub fn shift(&mut self, offset: Vector, fill: T) {
+        *self = self.shifted(offset, fill);
+    }
+
+    /// Returns a new grid `factor` times larger in each dimension, with each value of `self` repeated in a `factor` by `factor` block.
+    ///
+    /// Panics if `factor` is not positive or the new dimensions are too large.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::from_nested_iter([[1, 2], [3, 4]]);
+    ///
+    /// let scaled = grid.scale_nearest(2);
+    ///
+    /// assert_eq!(scaled.dim(), v(4, 4));
+    /// assert_eq!(
+    ///     scaled,
+    ///     Grid::from_nested_iter([[1, 1, 2, 2], [1, 1, 2, 2], [3, 3, 4, 4], [3, 3, 4, 4]])
+    /// );
+    /// assert!(scaled.positions().all(|pos| scaled[pos] == grid[pos / 2]));
+    /// ```
+    ///
+    /// ```should_panic
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::new(2, 1, 0);
+    ///
+    /// let _ = grid.scale_nearest(i64::MAX);
+    /// ```
+    #[track_caller]
+    pub fn scale_nearest(&self, factor: i64) -> Self {
+        assert!(factor > 0, "scale factor must be positive: {factor}");
+        let Some(dim) = self.dim.checked_mul(factor) else {
+            panic!("dimensions are too large: {} scaled by {factor}", self.dim);
+        };
+        Self::from_fn(dim.x, dim.y, |pos| self[pos / factor].clone())
+    }
+
+    /// Returns a new grid `factor` times smaller in each dimension, keeping the top-left value of each `factor` by `factor` block of `self`.
+    ///
+    /// If the dimensions of `self` are not multiples of `factor`, the partial blocks in the last column and row are kept,
+    /// so the new dimensions are rounded up.
+    ///
+    /// Panics if `factor` is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::from_fn(5, 4, |pos| pos.x + pos.y * 5);
+    ///
+    /// assert_eq!(
+    ///     grid.downscale_nearest(2),
+    ///     Grid::from_nested_iter([[0, 2, 4], [10, 12, 14]])
+    /// );
+    /// assert_eq!(grid.scale_nearest(3).downscale_nearest(3), grid);
+    /// assert_eq!(grid.downscale_nearest(i64::MAX), Grid::new(1, 1, 0));
+    /// ```
+    #[track_caller]
+    pub fn downscale_nearest(&self, factor: i64) -> Self {
+        assert!(factor > 0, "scale factor must be positive: {factor}");
+        let dim = (self.dim - Vector::new(1, 1)) / factor + Vector::new(1, 1);
+        Self::from_fn(dim.x, dim.y, |pos| self[pos * factor].clone())
+    }
+}
+
+impl<T> Grid<T> {
+    /// Mirrors the grid left-to-right in place, swapping columns `x` and `width - 1 - x`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<u8> = Grid::from_nested_iter([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// grid.flip_horizontal();
+    ///
+    /// assert_eq!(grid, Grid::from_nested_iter([[3, 2, 1], [6, 5, 4]]));
+    /// ```
+    pub fn flip_horizontal(&mut self) {
+        if self.raw.is_empty() {
+            return;
+        }
+        let width = self.width() as usize;
+        for row in self.raw.chunks_exact_mut(width) {
+            row.reverse();
+        }
+    }
+
+    /// Mirrors the grid top-to-bottom in place, swapping rows `y` and `height - 1 - y`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<u8> = Grid::from_nested_iter([[1, 2], [3, 4], [5, 6]]);
+    ///
+    /// grid.flip_vertical();
+    ///
+    /// assert_eq!(grid, Grid::from_nested_iter([[5, 6], [3, 4], [1, 2]]));
+    /// ```
+    pub fn flip_vertical(&mut self) {
+        let width = self.width() as usize;
+        let height = self.height() as usize;
+        for y in 0..height / 2 {
+            let (top, bottom) = self.raw.split_at_mut((height - 1 - y) * width);
+            top[y * width..(y + 1) * width].swap_with_slice(&mut bottom[..width]);
+        }
+    }
+
+    /// Moves every value in the grid from `pos` to `pos + offset` in place, wrapping around the edges as if the grid were a torus.
+    ///
+    /// Unlike [`Grid::shift`], no values are lost, so no fill value is needed.
+    /// Afterwards, the value at `pos` is the value that [`Grid::get_wrapping`] would previously have returned for `pos - offset`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<u8> = Grid::from_nested_iter([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// grid.rotate_by(SE);
+    ///
+    /// assert_eq!(grid, Grid::from_nested_iter([[6, 4, 5], [3, 1, 2]]));
+    ///
+    /// let original = grid.clone();
+    ///
+    /// grid.rotate_by(v(3, 0));
+    /// assert_eq!(grid, original);
+    ///
+    /// grid.rotate_by(v(0, -2));
+    /// assert_eq!(grid, original);
+    ///
+    /// grid.rotate_by(v(-7, 5));
+    /// assert_eq!(grid, original.pos_map(|pos, _| *original.get_wrapping(pos - v(-7, 5))));
+    /// ```
+    pub fn rotate_by(&mut self, offset: Vector) {
+        if self.raw.is_empty() {
+            return;
+        }
+        let shift = offset.rem_euclid(self.dim);
+        let width = self.width() as usize;
+        if shift.x != 0 {
+            for row in self.raw.chunks_exact_mut(width) {
+                row.rotate_right(shift.x as usize);
+            }
+        }
+        self.raw.rotate_right(shift.y as usize * width);
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Returns `true` if the grid is unchanged by [`Grid::flip_horizontal`], comparing values without copying the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// assert!(Grid::from_nested_iter([[1, 2, 1], [3, 4, 3]]).is_horizontally_symmetric());
+    /// assert!(!Grid::from_nested_iter([[1, 2, 1], [3, 4, 5]]).is_horizontally_symmetric());
+    /// ```
+    pub fn is_horizontally_symmetric(&self) -> bool {
+        self.rows().all(|row| {
+            let half = row.len() / 2;
+            row[..half].iter().eq(row[row.len() - half..].iter().rev())
+        })
+    }
+
+    /// Returns `true` if the grid is unchanged by [`Grid::flip_vertical`], comparing values without copying the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// assert!(Grid::from_nested_iter([[1, 2], [3, 4], [1, 2]]).is_vertically_symmetric());
+    /// assert!(!Grid::from_nested_iter([[1, 2], [3, 4], [2, 1]]).is_vertically_symmetric());
+    /// ```
+    pub fn is_vertically_symmetric(&self) -> bool {
+        let half = self.height() as usize / 2;
+        self.rows().take(half).eq(self.rows().rev().take(half))
+    }
+
+    /// Returns `true` if the grid is unchanged by [`Grid::rotate_180`], comparing values without copying the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid = Grid::from_nested_iter([[1, 2, 3], [4, 5, 4], [3, 2, 1]]);
+    ///
+    /// assert!(grid.is_rotationally_symmetric());
+    /// assert!(!grid.is_horizontally_symmetric());
+    /// assert!(!grid.is_vertically_symmetric());
+    ///
+    /// assert!(!Grid::from_nested_iter([[1, 2, 3], [3, 1, 2]]).is_rotationally_symmetric());
+    /// ```
+    pub fn is_rotationally_symmetric(&self) -> bool {
+        let half = self.raw.len() / 2;
+        self.raw[..half]
+            .iter()
+            .eq(self.raw[self.raw.len() - half..].iter().rev())
+    }
+}
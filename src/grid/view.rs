@@ -0,0 +1,394 @@
+//! Borrowed views of rectangular regions of a `Grid`.
+
+use crate::{
+    grid::{iterators::Positions, out_of_bounds, Grid},
+    vector::Vector,
+};
+
+use std::ops::{Index, IndexMut};
+
+impl<T> Grid<T> {
+    /// Returns a view of the rectangular region of the grid with the given top-left position and dimensions, without copying any values.
+    ///
+    /// The position `(0, 0)` in the view corresponds to `top_left` in the grid.
+    ///
+    /// Returns `None` if the dimensions are not positive or the region is not entirely within the bounds of the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<i64> = Grid::from_fn(4, 3, |pos| pos.x + pos.y * 4);
+    ///
+    /// let view = grid.view(v(1, 1), v(2, 2)).unwrap();
+    ///
+    /// assert_eq!(view.dim(), v(2, 2));
+    /// assert_eq!(view[v(0, 0)], 5);
+    /// assert_eq!(view.get(v(1, 1)), Some(&10));
+    /// assert_eq!(view.get(v(2, 1)), None);
+    ///
+    /// assert!(grid.view(v(3, 1), v(2, 1)).is_none());
+    /// ```
+    pub fn view(&self, top_left: Vector, dim: Vector) -> Option<GridView<'_, T>> {
+        self.contains_rect(top_left, dim).then_some(GridView {
+            grid: self,
+            top_left,
+            dim,
+        })
+    }
+
+    /// Returns a mutable view of the rectangular region of the grid with the given top-left position and dimensions, without copying any values.
+    ///
+    /// The position `(0, 0)` in the view corresponds to `top_left` in the grid.
+    ///
+    /// Returns `None` if the dimensions are not positive or the region is not entirely within the bounds of the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let mut grid: Grid<u8> = Grid::new(4, 3, 0);
+    ///
+    /// let mut view = grid.view_mut(v(2, 1), v(2, 2)).unwrap();
+    ///
+    /// view[v(0, 0)] = 1;
+    /// *view.get_mut(v(1, 1)).unwrap() = 2;
+    ///
+    /// assert_eq!(grid[v(2, 1)], 1);
+    /// assert_eq!(grid[v(3, 2)], 2);
+    /// ```
+    pub fn view_mut(&mut self, top_left: Vector, dim: Vector) -> Option<GridViewMut<'_, T>> {
+        self.contains_rect(top_left, dim).then_some(GridViewMut {
+            grid: self,
+            top_left,
+            dim,
+        })
+    }
+
+    /// Returns an iterator over views of every `size`-shaped region entirely within the grid, in row-major order of their top-left positions.
+    ///
+    /// There are `(width - size.x + 1) * (height - size.y + 1)` such regions.
+    /// The iterator is empty if `size` is not positive or exceeds the dimensions of the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::from_nested_iter([
+    ///     [0, 1, 0, 0],
+    ///     [1, 1, 1, 0],
+    ///     [0, 1, 0, 0],
+    /// ]);
+    ///
+    /// let plus = Grid::from_nested_iter([[0, 1, 0], [1, 1, 1], [0, 1, 0]]);
+    ///
+    /// let matches: Vec<Vector> = grid
+    ///     .windows(v(3, 3))
+    ///     .filter(|view| view.iter_positions().all(|(pos, value)| *value == plus[pos]))
+    ///     .map(|view| view.top_left())
+    ///     .collect();
+    ///
+    /// assert_eq!(matches, vec![v(0, 0)]);
+    /// assert_eq!(grid.windows(v(2, 2)).len(), 3 * 2);
+    /// assert_eq!(grid.windows(v(5, 1)).count(), 0);
+    /// ```
+    pub fn windows(&self, size: Vector) -> Windows<'_, T> {
+        let count = if self.contains_rect(Vector::new(0, 0), size) {
+            self.dim - size + Vector::new(1, 1)
+        } else {
+            Vector::new(0, 0)
+        };
+        Windows {
+            grid: self,
+            size,
+            positions: Positions::new(count),
+        }
+    }
+}
+
+/// A borrowed view of a rectangular region of a `Grid`, indexed relative to the top-left of the region.
+///
+/// The position `(0, 0)` in the view corresponds to the top-left position of the region in the grid.
+///
+/// # Examples
+///
+/// ```
+/// use grid::prelude::*;
+///
+/// let grid: Grid<i64> = Grid::from_fn(4, 4, |pos| pos.x * pos.y);
+///
+/// let view = grid.view(v(2, 2), v(2, 2)).unwrap();
+///
+/// let sum: i64 = view.iter_positions().map(|(_, value)| value).sum();
+///
+/// assert_eq!(sum, 4 + 6 + 6 + 9);
+/// ```
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    top_left: Vector,
+    dim: Vector,
+}
+
+impl<T> Clone for GridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridView<'_, T> {}
+
+impl<'a, T> GridView<'a, T> {
+    /// Returns the width of the view.
+    #[inline]
+    pub fn width(&self) -> i64 {
+        self.dim.x
+    }
+
+    /// Returns the height of the view.
+    #[inline]
+    pub fn height(&self) -> i64 {
+        self.dim.y
+    }
+
+    /// Returns the dimensions of the view.
+    #[inline]
+    pub fn dim(&self) -> Vector {
+        self.dim
+    }
+
+    /// Returns the position in the underlying grid of the top-left of the view.
+    #[inline]
+    pub fn top_left(&self) -> Vector {
+        self.top_left
+    }
+
+    /// Returns `true` if the given position is within the bounds of the view, or `false` otherwise.
+    pub fn in_bounds(&self, pos: Vector) -> bool {
+        (0..self.width()).contains(&pos.x) && (0..self.height()).contains(&pos.y)
+    }
+
+    /// Returns a reference to the value at the given position of the view, or `None` if out of bounds.
+    pub fn get(&self, pos: Vector) -> Option<&'a T> {
+        let grid = self.grid;
+        self.in_bounds(pos).then(|| &grid[self.top_left + pos])
+    }
+
+    /// Returns an iterator over every position in the view and its value, in row-major order.
+    ///
+    /// Values from this iterator come in the form of a tuple containing the position relative to the view and a reference to the value:
+    /// `(Vector, &T)`
+    pub fn iter_positions(&self) -> ViewPositionIter<'a, T> {
+        ViewPositionIter {
+            view: *self,
+            positions: Positions::new(self.dim),
+        }
+    }
+}
+
+impl<T> Index<Vector> for GridView<'_, T> {
+    type Output = T;
+
+    #[track_caller]
+    fn index(&self, pos: Vector) -> &Self::Output {
+        let dim = self.dim;
+        match self.get(pos) {
+            Some(value) => value,
+            None => out_of_bounds(pos, dim),
+        }
+    }
+}
+
+/// A mutable borrowed view of a rectangular region of a `Grid`, indexed relative to the top-left of the region.
+///
+/// The position `(0, 0)` in the view corresponds to the top-left position of the region in the grid.
+///
+/// # Examples
+///
+/// ```
+/// use grid::prelude::*;
+///
+/// let mut grid: Grid<u8> = Grid::new(4, 4, 0);
+///
+/// let mut view = grid.view_mut(v(1, 1), v(2, 2)).unwrap();
+///
+/// for y in 0..view.height() {
+///     for x in 0..view.width() {
+///         view[v(x, y)] = 1;
+///     }
+/// }
+///
+/// assert_eq!(grid.count_value(&1), 4);
+/// assert_eq!(grid[v(2, 2)], 1);
+/// assert_eq!(grid[v(3, 3)], 0);
+/// ```
+pub struct GridViewMut<'a, T> {
+    grid: &'a mut Grid<T>,
+    top_left: Vector,
+    dim: Vector,
+}
+
+impl<T> GridViewMut<'_, T> {
+    /// Returns the width of the view.
+    #[inline]
+    pub fn width(&self) -> i64 {
+        self.dim.x
+    }
+
+    /// Returns the height of the view.
+    #[inline]
+    pub fn height(&self) -> i64 {
+        self.dim.y
+    }
+
+    /// Returns the dimensions of the view.
+    #[inline]
+    pub fn dim(&self) -> Vector {
+        self.dim
+    }
+
+    /// Returns the position in the underlying grid of the top-left of the view.
+    #[inline]
+    pub fn top_left(&self) -> Vector {
+        self.top_left
+    }
+
+    /// Returns `true` if the given position is within the bounds of the view, or `false` otherwise.
+    pub fn in_bounds(&self, pos: Vector) -> bool {
+        (0..self.width()).contains(&pos.x) && (0..self.height()).contains(&pos.y)
+    }
+
+    /// Returns a reference to the value at the given position of the view, or `None` if out of bounds.
+    pub fn get(&self, pos: Vector) -> Option<&T> {
+        self.in_bounds(pos).then(|| &self.grid[self.top_left + pos])
+    }
+
+    /// Returns a mutable reference to the value at the given position of the view, or `None` if out of bounds.
+    pub fn get_mut(&mut self, pos: Vector) -> Option<&mut T> {
+        self.in_bounds(pos)
+            .then(|| &mut self.grid[self.top_left + pos])
+    }
+
+    /// Returns an immutable view of the same region.
+    pub fn as_view(&self) -> GridView<'_, T> {
+        GridView {
+            grid: self.grid,
+            top_left: self.top_left,
+            dim: self.dim,
+        }
+    }
+
+    /// Returns an iterator over every position in the view and its value, in row-major order.
+    ///
+    /// Values from this iterator come in the form of a tuple containing the position relative to the view and a reference to the value:
+    /// `(Vector, &T)`
+    pub fn iter_positions(&self) -> ViewPositionIter<'_, T> {
+        self.as_view().iter_positions()
+    }
+}
+
+impl<T> Index<Vector> for GridViewMut<'_, T> {
+    type Output = T;
+
+    #[track_caller]
+    fn index(&self, pos: Vector) -> &Self::Output {
+        let dim = self.dim;
+        match self.get(pos) {
+            Some(value) => value,
+            None => out_of_bounds(pos, dim),
+        }
+    }
+}
+
+impl<T> IndexMut<Vector> for GridViewMut<'_, T> {
+    #[track_caller]
+    fn index_mut(&mut self, pos: Vector) -> &mut Self::Output {
+        let dim = self.dim;
+        match self.get_mut(pos) {
+            Some(value) => value,
+            None => out_of_bounds(pos, dim),
+        }
+    }
+}
+
+/// An iterator over every position in a view and its value, in row-major order.
+///
+/// Values from this iterator come in the form of a tuple containing the position relative to the view and a reference to the value:
+/// `(Vector, &T)`
+///
+/// # Examples
+///
+/// ```
+/// use grid::prelude::*;
+///
+/// let grid: Grid<i64> = Grid::from_fn(4, 4, |pos| pos.x + pos.y * 4);
+///
+/// let mut iter = grid.view(v(1, 2), v(2, 1)).unwrap().iter_positions();
+///
+/// assert_eq!(iter.next(), Some((v(0, 0), &9)));
+/// assert_eq!(iter.next(), Some((v(1, 0), &10)));
+/// assert_eq!(iter.next(), None);
+/// ```
+pub struct ViewPositionIter<'a, T> {
+    view: GridView<'a, T>,
+    positions: Positions,
+}
+
+impl<'a, T> Iterator for ViewPositionIter<'a, T> {
+    type Item = (Vector, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pos = self.positions.next()?;
+        Some((pos, &self.view.grid[self.view.top_left + pos]))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.positions.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for ViewPositionIter<'_, T> {}
+
+/// An iterator over views of every fixed-size region entirely within a grid, in row-major order of their top-left positions.
+///
+/// # Examples
+///
+/// ```
+/// use grid::prelude::*;
+///
+/// let grid: Grid<i64> = Grid::from_fn(4, 3, |pos| pos.x + pos.y * 4);
+///
+/// let sums: Vec<i64> = grid
+///     .windows(v(3, 2))
+///     .map(|view| view.iter_positions().map(|(_, value)| value).sum())
+///     .collect();
+///
+/// assert_eq!(sums, vec![18, 24, 42, 48]);
+/// ```
+pub struct Windows<'a, T> {
+    grid: &'a Grid<T>,
+    size: Vector,
+    positions: Positions,
+}
+
+impl<'a, T> Iterator for Windows<'a, T> {
+    type Item = GridView<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(GridView {
+            grid: self.grid,
+            top_left: self.positions.next()?,
+            dim: self.size,
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.positions.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for Windows<'_, T> {}
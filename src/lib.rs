@@ -34,13 +34,49 @@
 //!
 //! assert_eq!(pos, v(5, 2));
 //! ```
+//!
+//! # Features
+//!
+//! * `image`: adds `Grid::to_image` and `Grid::from_image` for converting between a grid and an `image::RgbImage`.
+//! * `rand`: adds `Grid::random` and `Grid::random_with` for initialising a grid from a random number generator.
+//! * `rayon`: adds `Grid::par_from_fn`, `Grid::par_map`, `Grid::par_iter_mut` and `Grid::par_iter_mut_positions` for computing values in parallel.
+//! * `serde`: implements `Serialize` and `Deserialize` for `Grid<T>` and `Vector`.
+//!   A grid is represented as `{ "width": w, "height": h, "data": [...] }`, with `data` in row-major order.
+//!   The empty `(0, 0)` grid that `Grid::from_nested_iter` can construct round-trips, but no other grid with a zero
+//!   dimension is accepted.
+//!
+//! ```
+//! # #[cfg(feature = "serde")] {
+//! use grid::prelude::*;
+//!
+//! let grid: Grid<i64> = Grid::from_fn(3, 2, |pos| pos.x + pos.y * 3);
+//!
+//! let json = serde_json::to_string(&grid).unwrap();
+//!
+//! assert_eq!(json, r#"{"width":3,"height":2,"data":[0,1,2,3,4,5]}"#);
+//! assert_eq!(serde_json::from_str::<Grid<i64>>(&json).unwrap(), grid);
+//!
+//! assert!(serde_json::from_str::<Grid<i64>>(r#"{"width":3,"height":2,"data":[0,1]}"#).is_err());
+//! assert!(serde_json::from_str::<Grid<i64>>(r#"{"width":2,"height":0,"data":[]}"#).is_err());
+//!
+//! assert!(serde_json::from_str::<Grid<i64>>(r#"{"width":0,"height":9223372036854775807,"data":[]}"#).is_err());
+//!
+//! let empty: Grid<i64> = Grid::from_nested_iter([[], []]);
+//! let json = serde_json::to_string(&empty).unwrap();
+//!
+//! assert_eq!(json, r#"{"width":0,"height":0,"data":[]}"#);
+//! assert_eq!(serde_json::from_str::<Grid<i64>>(&json).unwrap(), empty);
+//!
+//! assert_eq!(serde_json::to_string(&v(4, -1)).unwrap(), r#"{"x":4,"y":-1}"#);
+//! # }
+//! ```
 
 mod grid;
 mod vector;
 
-pub use crate::grid::iterators;
+pub use crate::grid::{checked_size, convolve, error, iterators, view};
 
 pub mod prelude {
-    pub use crate::grid::Grid;
-    pub use crate::vector::{constants::*, v, Vector};
+    pub use crate::grid::{convolve::EdgeMode, error::GridError, Grid};
+    pub use crate::vector::{constants::*, v, Direction, Vector};
 }
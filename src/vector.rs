@@ -1,6 +1,9 @@
 //! A 2D vector struct with `i64` components.
 
 pub mod constants;
+mod direction;
+
+pub use direction::Direction;
 
 use std::{
     fmt,
@@ -25,6 +28,7 @@ use std::{
 /// assert_eq!(v, Vector::new(10, 28));
 /// ```
 #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vector {
     pub x: i64,
     pub y: i64,
@@ -52,12 +56,198 @@ impl Vector {
         diff.x.abs() + diff.y.abs()
     }
 
+    /// Computes the chebyshev distance from `self` to `rhs`.
+    ///
+    /// This is the number of steps between the two positions when diagonal moves are allowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let knight = v(1, 2);
+    ///
+    /// assert_eq!(ZERO.manhattan(knight), 3);
+    /// assert_eq!(ZERO.chebyshev(knight), 2);
+    /// assert_eq!(v(3, -1).chebyshev(v(-2, 1)), 5);
+    /// ```
+    pub fn chebyshev(self, rhs: Self) -> i64 {
+        let diff = self - rhs;
+        diff.x.abs().max(diff.y.abs())
+    }
+
+    /// Computes the manhattan distance from the origin to `self`.
+    #[inline]
+    pub fn magnitude_manhattan(self) -> i64 {
+        self.x.abs() + self.y.abs()
+    }
+
+    /// Returns an iterator over every position at exactly the given manhattan distance from `self`.
+    ///
+    /// The positions form a diamond, yielded clockwise starting from the eastmost position.
+    /// A radius of `0` yields only `self`, and a negative radius yields nothing.
+    ///
+    /// Panics if `radius` is greater than `i64::MAX / 4`, as the ring would have more than `i64::MAX` positions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let ring: Vec<Vector> = v(3, 3).manhattan_ring(2).collect();
+    ///
+    /// assert_eq!(ring.len(), 8);
+    /// assert_eq!(ring[..3], [v(5, 3), v(4, 4), v(3, 5)]);
+    /// assert!(ring.iter().all(|&pos| pos.manhattan(v(3, 3)) == 2));
+    ///
+    /// assert_eq!(v(3, 3).manhattan_ring(0).collect::<Vec<_>>(), vec![v(3, 3)]);
+    /// assert_eq!(v(3, 3).manhattan_ring(-1).count(), 0);
+    /// assert_eq!(ZERO.manhattan_ring(i64::MAX / 4).next(), Some(v(i64::MAX / 4, 0)));
+    /// ```
+    #[track_caller]
+    pub fn manhattan_ring(self, radius: i64) -> impl Iterator<Item = Vector> {
+        let Some(len) = radius.max(0).checked_mul(4) else {
+            panic!("radius is too large: {radius}");
+        };
+        let centre = (radius == 0).then_some(self);
+        let ring = (0..len).map(move |k| {
+            let (side, i) = (k / radius, k % radius);
+            self + match side {
+                0 => Self::new(radius - i, i),
+                1 => Self::new(-i, radius - i),
+                2 => Self::new(i - radius, -i),
+                _ => Self::new(i, i - radius),
+            }
+        });
+        centre.into_iter().chain(ring)
+    }
+
+    /// Returns an iterator over every position within the given manhattan distance of `self`, including `self`.
+    ///
+    /// The positions are yielded in order of increasing distance, as a sequence of [`Vector::manhattan_ring`]s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let grid: Grid<u8> = Grid::new(5, 5, 0);
+    ///
+    /// assert_eq!(ZERO.manhattan_disk(3).count(), 25);
+    /// assert_eq!(v(0, 0).manhattan_disk(2).filter(|&pos| grid.in_bounds(pos)).count(), 6);
+    /// assert_eq!(v(2, 2).manhattan_disk(1).collect::<Vec<_>>(), vec![v(2, 2), v(3, 2), v(2, 3), v(1, 2), v(2, 1)]);
+    /// ```
+    pub fn manhattan_disk(self, radius: i64) -> impl Iterator<Item = Vector> {
+        (0..=radius).flat_map(move |r| self.manhattan_ring(r))
+    }
+
+    /// Returns `true` if both coordinates of `self` are `0`.
+    #[inline]
+    pub fn is_zero(self) -> bool {
+        self.x == 0 && self.y == 0
+    }
+
+    /// Returns `true` if `self` is one of the eight unit steps in [`ADJACENT`](constants::ADJACENT).
+    #[inline]
+    pub fn is_unit(self) -> bool {
+        self.x.abs().max(self.y.abs()) == 1
+    }
+
+    /// Returns `true` if `self` is one of the four unit steps in [`ORTHOGONAL`](constants::ORTHOGONAL).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// assert!(ORTHOGONAL.iter().all(|dir| dir.is_unit_orthogonal() && dir.is_unit()));
+    /// assert!(!ORTHOGONAL.iter().any(|dir| dir.is_unit_diagonal() || dir.is_zero()));
+    ///
+    /// assert!(!v(2, 0).is_zero());
+    /// assert!(!v(2, 0).is_unit());
+    /// assert!(!v(2, 0).is_unit_orthogonal());
+    /// assert!(ZERO.is_zero() && !ZERO.is_unit());
+    /// ```
+    #[inline]
+    pub fn is_unit_orthogonal(self) -> bool {
+        self.magnitude_manhattan() == 1
+    }
+
+    /// Returns `true` if `self` is one of the four unit steps in [`DIAGONAL`](constants::DIAGONAL).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// assert!(DIAGONAL.iter().all(|dir| dir.is_unit_diagonal() && dir.is_unit()));
+    /// assert!(!DIAGONAL.iter().any(|dir| dir.is_unit_orthogonal() || dir.is_zero()));
+    ///
+    /// assert!(!v(2, 2).is_unit_diagonal());
+    /// assert!(!v(1, 2).is_unit());
+    /// ```
+    #[inline]
+    pub fn is_unit_diagonal(self) -> bool {
+        self.x.abs() == 1 && self.y.abs() == 1
+    }
+
+    /// Returns the compass label of `self` if it is one of the eight unit steps in
+    /// [`ADJACENT`](constants::ADJACENT), or `None` otherwise.
+    ///
+    /// See also [`Direction::abbreviation`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let labels: Vec<&str> = ADJACENT.iter().filter_map(|dir| dir.compass()).collect();
+    ///
+    /// assert_eq!(labels, vec!["E", "NE", "N", "NW", "W", "SW", "S", "SE"]);
+    /// assert_eq!(ZERO.compass(), None);
+    /// assert_eq!(v(0, 2).compass(), None);
+    /// ```
+    pub fn compass(self) -> Option<&'static str> {
+        Direction::from_vector(self).map(Direction::abbreviation)
+    }
+
     /// Returns a vector containing the signs of each coordinate of `self`.
     #[inline]
     pub fn signum(self) -> Self {
         Self::new(self.x.signum(), self.y.signum())
     }
 
+    /// Returns `self` divided by the greatest common divisor of its coordinates, giving the smallest integer step in the same direction.
+    ///
+    /// Signs are preserved, a vector with one zero coordinate reduces to a unit step along the other axis, and `ZERO` reduces to `ZERO`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// assert_eq!(v(4, 2).reduced(), v(2, 1));
+    /// assert_eq!(v(-6, 9).reduced(), v(-2, 3));
+    /// assert_eq!(v(-4, -4).reduced(), NW);
+    /// assert_eq!(v(0, 5).reduced(), SOUTH);
+    /// assert_eq!(v(3, 7).reduced(), v(3, 7));
+    /// assert_eq!(ZERO.reduced(), ZERO);
+    /// assert_eq!(v(i64::MIN, 0).reduced(), WEST);
+    /// assert_eq!(v(i64::MIN, i64::MIN / 2).reduced(), v(-2, -1));
+    /// ```
+    pub fn reduced(self) -> Self {
+        let (mut a, mut b) = (self.x.unsigned_abs(), self.y.unsigned_abs());
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        match i64::try_from(a) {
+            Ok(0) => self,
+            Ok(gcd) => self / gcd,
+            // the only divisor too large for an `i64` is 2^63, which only divides 0 and `i64::MIN`
+            Err(_) => self.signum(),
+        }
+    }
+
     /// Returns a vector containing the minimum values of each coordinate of `self` and `rhs`.
     #[inline]
     pub fn min(self, rhs: Self) -> Self {
@@ -78,6 +268,129 @@ impl Vector {
         Self::new(self.x.clamp(min.x, max.x), self.y.clamp(min.y, max.y))
     }
 
+    /// Returns a vector containing the products of each coordinate of `self` and `rhs`.
+    #[inline]
+    pub fn component_mul(self, rhs: Self) -> Self {
+        Self::new(self.x * rhs.x, self.y * rhs.y)
+    }
+
+    /// Returns a vector containing the quotients of each coordinate of `self` divided by `rhs`, rounded towards zero.
+    ///
+    /// Panics if either coordinate of `rhs` is `0`.
+    #[inline]
+    #[track_caller]
+    pub fn component_div(self, rhs: Self) -> Self {
+        Self::new(self.x / rhs.x, self.y / rhs.y)
+    }
+
+    /// Returns a vector containing the non-negative remainders of each coordinate of `self` divided by `rhs`.
+    ///
+    /// Panics if either coordinate of `rhs` is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// assert_eq!(v(7, -1).rem_euclid(v(4, 3)), v(3, 2));
+    /// assert_eq!(v(7, -1).component_div(v(4, 3)), v(1, 0));
+    /// assert_eq!(v(7, -1).component_mul(v(4, 3)), v(28, -3));
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        Self::new(self.x.rem_euclid(rhs.x), self.y.rem_euclid(rhs.y))
+    }
+
+    /// Adds `rhs` to `self`, returning `None` if either coordinate overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// assert_eq!(v(1, 2).checked_add(v(3, 4)), Some(v(4, 6)));
+    /// assert_eq!(v(i64::MAX, 0).checked_add(EAST), None);
+    /// assert_eq!(v(0, i64::MIN).checked_add(NORTH), None);
+    /// assert_eq!(v(i64::MAX - 1, 0).checked_add(EAST), Some(v(i64::MAX, 0)));
+    /// ```
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add(rhs.x)?,
+            self.y.checked_add(rhs.y)?,
+        ))
+    }
+
+    /// Subtracts `rhs` from `self`, returning `None` if either coordinate overflows.
+    #[inline]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_sub(rhs.x)?,
+            self.y.checked_sub(rhs.y)?,
+        ))
+    }
+
+    /// Multiplies both coordinates of `self` by `rhs`, returning `None` if either coordinate overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// assert_eq!(v(2, -3).checked_mul(4), Some(v(8, -12)));
+    /// assert_eq!(v(1, i64::MAX / 2 + 1).checked_mul(2), None);
+    /// ```
+    #[inline]
+    pub fn checked_mul(self, rhs: i64) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_mul(rhs)?,
+            self.y.checked_mul(rhs)?,
+        ))
+    }
+
+    /// Adds `rhs` to `self`, saturating each coordinate at the numeric bounds instead of overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// assert_eq!(v(i64::MAX, 1).saturating_add(v(5, 5)), v(i64::MAX, 6));
+    /// assert_eq!(v(i64::MIN, 0).saturating_sub(EAST), v(i64::MIN, 0));
+    /// ```
+    #[inline]
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self::new(self.x.saturating_add(rhs.x), self.y.saturating_add(rhs.y))
+    }
+
+    /// Subtracts `rhs` from `self`, saturating each coordinate at the numeric bounds instead of overflowing.
+    #[inline]
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self::new(self.x.saturating_sub(rhs.x), self.y.saturating_sub(rhs.y))
+    }
+
+    /// Adds `rhs` to `self`, wrapping each coordinate around at the numeric bounds instead of overflowing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// assert_eq!(v(i64::MAX, 1).wrapping_add(EAST), v(i64::MIN, 1));
+    /// assert_eq!(v(i64::MIN, 1).wrapping_sub(EAST), v(i64::MAX, 1));
+    /// ```
+    #[inline]
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        Self::new(self.x.wrapping_add(rhs.x), self.y.wrapping_add(rhs.y))
+    }
+
+    /// Subtracts `rhs` from `self`, wrapping each coordinate around at the numeric bounds instead of overflowing.
+    #[inline]
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        Self::new(self.x.wrapping_sub(rhs.x), self.y.wrapping_sub(rhs.y))
+    }
+
     /// Computes the dot product of `self` and `rhs`.
     #[inline]
     pub fn dot(self, rhs: Self) -> i64 {
@@ -85,17 +398,130 @@ impl Vector {
     }
 
     /// Returns a vector that is equal to `self` rotated by 90 degrees: (x, y) -> (-y, x)
+    ///
+    /// Since `y` points down in a grid, this is a clockwise rotation, the same as [`Vector::rotate_cw`].
     #[inline]
     pub fn perp(self) -> Self {
         Self::new(-self.y, self.x)
     }
 
+    /// Returns a vector that is equal to `self` rotated 90 degrees clockwise: (x, y) -> (-y, x)
+    ///
+    /// This assumes that `x` points right and `y` points down, as in a grid, so `EAST` rotates to `SOUTH`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// assert_eq!(EAST.rotate_cw(), SOUTH);
+    /// assert_eq!(SOUTH.rotate_cw(), WEST);
+    /// assert_eq!(v(2, -1).rotate_cw(), v(1, 2));
+    /// ```
+    #[inline]
+    pub fn rotate_cw(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Returns a vector that is equal to `self` rotated 90 degrees counter-clockwise: (x, y) -> (y, -x)
+    ///
+    /// This assumes that `x` points right and `y` points down, as in a grid, so `EAST` rotates to `NORTH`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// assert_eq!(EAST.rotate_ccw(), NORTH);
+    /// assert_eq!(NORTH.rotate_ccw(), WEST);
+    /// assert_eq!(v(2, -1).rotate_ccw().rotate_cw(), v(2, -1));
+    /// ```
+    #[inline]
+    pub fn rotate_ccw(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    /// Returns a vector that is equal to `self` rotated 180 degrees: (x, y) -> (-x, -y)
+    #[inline]
+    pub fn rotate_180(self) -> Self {
+        -self
+    }
+
+    /// Returns the four rotations of `self` in clockwise order, starting with `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// assert_eq!(EAST.rotations(), [EAST, SOUTH, WEST, NORTH]);
+    /// ```
+    #[inline]
+    pub fn rotations(self) -> [Self; 4] {
+        [self, self.rotate_cw(), self.rotate_180(), self.rotate_ccw()]
+    }
+
     /// Computes the perpendicular dot product of `self` and `rhs`.
     #[inline]
     pub fn perp_dot(self, rhs: Self) -> i64 {
         (self.x * rhs.y) - (self.y * rhs.x)
     }
 
+    /// Computes the squared euclidean length of `self`.
+    #[inline]
+    pub fn length_squared(self) -> i64 {
+        self.dot(self)
+    }
+
+    /// Computes the euclidean length of `self`.
+    ///
+    /// The length of the zero vector is `0.0`.
+    #[inline]
+    pub fn length(self) -> f64 {
+        (self.x as f64).hypot(self.y as f64)
+    }
+
+    /// Computes the angle of `self` from the positive `x` axis in radians, in the range `-π..=π`.
+    ///
+    /// Since `y` points down in a grid, positive angles are clockwise. The angle of the zero vector is `0.0`.
+    #[inline]
+    pub fn angle(self) -> f64 {
+        (self.y as f64).atan2(self.x as f64)
+    }
+
+    /// Computes the unsigned angle between `self` and `rhs` in radians, in the range `0..=π`.
+    ///
+    /// The angle between the zero vector and any other vector is `0.0`.
+    #[inline]
+    pub fn angle_between(self, rhs: Self) -> f64 {
+        (self.perp_dot(rhs).abs() as f64).atan2(self.dot(rhs) as f64)
+    }
+
+    /// Converts the coordinates of `self` to `f64`.
+    #[inline]
+    pub fn as_f64(self) -> (f64, f64) {
+        (self.x as f64, self.y as f64)
+    }
+
+    /// Linearly interpolates between `self` and `rhs`, returning `self` when `t` is `0.0` and `rhs` when `t` is `1.0`.
+    ///
+    /// The result is not rounded, so it is returned as a pair of `f64` coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// assert_eq!(v(2, 4).lerp(v(4, -2), 0.5), (3.0, 1.0));
+    /// assert_eq!(v(2, 4).lerp(v(4, -2), 0.0), v(2, 4).as_f64());
+    /// ```
+    #[inline]
+    pub fn lerp(self, rhs: Self, t: f64) -> (f64, f64) {
+        let (x0, y0) = self.as_f64();
+        let (x1, y1) = rhs.as_f64();
+        (x0 + (x1 - x0) * t, y0 + (y1 - y0) * t)
+    }
+
     /// Returns a vector equal to `self` with a `y` value of `0`.
     #[inline]
     pub fn horizontal(self) -> Self {
@@ -115,6 +541,58 @@ impl fmt::Display for Vector {
     }
 }
 
+/// Converts an `(x, y)` tuple into a `Vector`.
+///
+/// # Examples
+///
+/// ```
+/// use grid::prelude::*;
+///
+/// assert_eq!(Vector::from((3, -2)), v(3, -2));
+/// assert_eq!(Vector::from([3, -2]), v(3, -2));
+/// assert_eq!(Vector::from((3i32, -2i32)), v(3, -2));
+///
+/// let (x, y) = v(3, -2).into();
+/// assert_eq!((x, y), (3, -2));
+///
+/// let [x, y]: [i64; 2] = v(3, -2).into();
+/// assert_eq!([x, y], [3, -2]);
+/// ```
+impl From<(i64, i64)> for Vector {
+    #[inline]
+    fn from((x, y): (i64, i64)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl From<(i32, i32)> for Vector {
+    #[inline]
+    fn from((x, y): (i32, i32)) -> Self {
+        Self::new(x.into(), y.into())
+    }
+}
+
+impl From<[i64; 2]> for Vector {
+    #[inline]
+    fn from([x, y]: [i64; 2]) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl From<Vector> for (i64, i64) {
+    #[inline]
+    fn from(v: Vector) -> Self {
+        (v.x, v.y)
+    }
+}
+
+impl From<Vector> for [i64; 2] {
+    #[inline]
+    fn from(v: Vector) -> Self {
+        [v.x, v.y]
+    }
+}
+
 /// A convenience function for creating a `Vector` with `Vector::new()`.
 ///
 /// # Examples
@@ -1,18 +1,18 @@
 //! Useful `Vector` constants for traversing 2D space.
 
-use crate::vector::Vector;
+use crate::vector::{Direction, Vector};
 
 pub const ZERO: Vector = Vector::new(0, 0);
 
-pub const EAST: Vector = Vector::new(1, 0);
-pub const NORTH: Vector = Vector::new(0, -1);
-pub const WEST: Vector = Vector::new(-1, 0);
-pub const SOUTH: Vector = Vector::new(0, 1);
+pub const EAST: Vector = Direction::East.to_vector();
+pub const NORTH: Vector = Direction::North.to_vector();
+pub const WEST: Vector = Direction::West.to_vector();
+pub const SOUTH: Vector = Direction::South.to_vector();
 
-pub const NE: Vector = Vector::new(1, -1);
-pub const NW: Vector = Vector::new(-1, -1);
-pub const SW: Vector = Vector::new(-1, 1);
-pub const SE: Vector = Vector::new(1, 1);
+pub const NE: Vector = Direction::NorthEast.to_vector();
+pub const NW: Vector = Direction::NorthWest.to_vector();
+pub const SW: Vector = Direction::SouthWest.to_vector();
+pub const SE: Vector = Direction::SouthEast.to_vector();
 
 pub const ORTHOGONAL: [Vector; 4] = [EAST, NORTH, WEST, SOUTH];
 pub const DIAGONAL: [Vector; 4] = [NE, NW, SW, SE];
@@ -0,0 +1,157 @@
+//! A compass direction type for traversing 2D space.
+
+use crate::vector::Vector;
+
+use std::array;
+
+/// One of the eight compass directions, with `y` increasing southwards.
+///
+/// # Examples
+///
+/// ```
+/// use grid::prelude::*;
+///
+/// let mut facing = Direction::North;
+/// let mut pos = ZERO;
+///
+/// for _ in 0..3 {
+///     pos += facing.to_vector();
+///     facing = facing.turn_right();
+/// }
+///
+/// assert_eq!(pos, v(1, 0));
+/// assert_eq!(facing, Direction::West);
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// Returns the unit `Vector` pointing in this direction.
+    pub const fn to_vector(self) -> Vector {
+        match self {
+            Self::North => Vector::new(0, -1),
+            Self::NorthEast => Vector::new(1, -1),
+            Self::East => Vector::new(1, 0),
+            Self::SouthEast => Vector::new(1, 1),
+            Self::South => Vector::new(0, 1),
+            Self::SouthWest => Vector::new(-1, 1),
+            Self::West => Vector::new(-1, 0),
+            Self::NorthWest => Vector::new(-1, -1),
+        }
+    }
+
+    /// Returns the direction whose unit `Vector` is `vector`, or `None` if there is no such direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// assert_eq!(Direction::from_vector(v(-1, 1)), Some(Direction::SouthWest));
+    /// assert_eq!(Direction::from_vector(v(2, 0)), None);
+    /// assert_eq!(Direction::from_vector(ZERO), None);
+    /// ```
+    pub fn from_vector(vector: Vector) -> Option<Self> {
+        Self::all().find(|dir| dir.to_vector() == vector)
+    }
+
+    /// Returns the short compass label for `self`, such as `"N"` or `"SW"`.
+    pub const fn abbreviation(self) -> &'static str {
+        match self {
+            Self::North => "N",
+            Self::NorthEast => "NE",
+            Self::East => "E",
+            Self::SouthEast => "SE",
+            Self::South => "S",
+            Self::SouthWest => "SW",
+            Self::West => "W",
+            Self::NorthWest => "NW",
+        }
+    }
+
+    /// Returns the direction 90 degrees anticlockwise from `self`.
+    pub fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    /// Returns the direction 90 degrees clockwise from `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// assert_eq!(Direction::North.turn_right(), Direction::East);
+    /// assert_eq!(Direction::SouthWest.turn_right(), Direction::NorthWest);
+    /// assert_eq!(
+    ///     Direction::East.turn_right().to_vector(),
+    ///     Direction::East.to_vector().rotate_cw()
+    /// );
+    /// ```
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// Returns the direction pointing the opposite way to `self`.
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+
+    /// Returns `true` if `self` is one of the four diagonal directions.
+    pub fn is_diagonal(self) -> bool {
+        self as u8 % 2 == 1
+    }
+
+    /// Returns an iterator over all eight directions, clockwise from `North`.
+    pub fn all() -> array::IntoIter<Self, 8> {
+        Self::ALL.into_iter()
+    }
+
+    /// Returns an iterator over the four orthogonal directions, clockwise from `North`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use grid::prelude::*;
+    ///
+    /// let vectors: Vec<Vector> = Direction::orthogonal().map(Direction::to_vector).collect();
+    ///
+    /// assert_eq!(vectors, vec![NORTH, EAST, SOUTH, WEST]);
+    /// assert_eq!(Direction::all().filter(|dir| !dir.is_diagonal()).count(), 4);
+    /// ```
+    pub fn orthogonal() -> array::IntoIter<Self, 4> {
+        [Self::North, Self::East, Self::South, Self::West].into_iter()
+    }
+
+    const ALL: [Self; 8] = [
+        Self::North,
+        Self::NorthEast,
+        Self::East,
+        Self::SouthEast,
+        Self::South,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+    ];
+
+    fn rotate(self, eighths: u8) -> Self {
+        Self::ALL[((self as u8 + eighths) % 8) as usize]
+    }
+}
+
+impl From<Direction> for Vector {
+    #[inline]
+    fn from(dir: Direction) -> Self {
+        dir.to_vector()
+    }
+}